@@ -13,6 +13,7 @@
 //! - Result caching by (prompt, response, metric) key
 //! - Custom rubric support
 //! - Cost tracking per evaluation
+//! - Per-model token-bucket rate limiting
 //! - Comprehensive error handling
 
 use crate::providers::{
@@ -22,10 +23,12 @@ use chrono::{DateTime, Duration, Utc};
 use lru::LruCache;
 use serde::{Deserialize, Serialize};
 use siphasher::sip::SipHasher13;
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use thiserror::Error;
 
 /// LLM-as-Judge errors
@@ -82,6 +85,23 @@ pub struct JudgeConfig {
 
     /// Maximum cost per evaluation (USD)
     pub max_cost_per_evaluation: Option<f64>,
+
+    /// Token-bucket rate limits, keyed by judge model
+    ///
+    /// When a model has no entry here, calls to that model are unthrottled.
+    pub rate_limits: HashMap<String, RateLimitSettings>,
+
+    /// Cache TTL overrides in hours, keyed by metric name
+    ///
+    /// Takes priority over `model_ttl_hours` and `cache_ttl_hours` when an
+    /// evaluation's metric has an entry here.
+    pub metric_ttl_hours: HashMap<String, i64>,
+
+    /// Cache TTL overrides in hours, keyed by judge model
+    ///
+    /// Consulted when `metric_ttl_hours` has no entry for the metric being
+    /// evaluated; falls back to `cache_ttl_hours` otherwise.
+    pub model_ttl_hours: HashMap<String, i64>,
 }
 
 impl Default for JudgeConfig {
@@ -95,10 +115,23 @@ impl Default for JudgeConfig {
             cache_ttl_hours: 168, // 7 days
             max_cache_size: 10_000,
             max_cost_per_evaluation: Some(0.10),
+            rate_limits: HashMap::new(),
+            metric_ttl_hours: HashMap::new(),
+            model_ttl_hours: HashMap::new(),
         }
     }
 }
 
+/// Token-bucket settings for a single judge model
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitSettings {
+    /// Tokens added to the bucket per second
+    pub rate: f64,
+
+    /// Maximum number of tokens the bucket can hold
+    pub burst: f64,
+}
+
 impl JudgeConfig {
     /// Create a new judge configuration
     pub fn new(model: impl Into<String>) -> Self {
@@ -137,6 +170,123 @@ impl JudgeConfig {
         self.max_cost_per_evaluation = Some(max_cost);
         self
     }
+
+    /// Configure a token-bucket rate limit for a specific judge model
+    ///
+    /// `rate` is the number of call tokens refilled per second; `burst` is the
+    /// bucket capacity, i.e. the maximum number of calls that can be made back
+    /// to back before further calls must wait for a refill. Each model keeps
+    /// an independent bucket, so rate-limiting `gpt-4` has no effect on calls
+    /// made to `gpt-3.5-turbo`. A non-positive `rate` never refills the
+    /// bucket, so once `burst` calls are made that model is blocked forever
+    /// rather than causing a panic.
+    pub fn with_rate_limit(mut self, model: impl Into<String>, rate: f64, burst: f64) -> Self {
+        self.rate_limits
+            .insert(model.into(), RateLimitSettings { rate, burst });
+        self
+    }
+
+    /// Override the cache TTL for a specific metric
+    pub fn with_metric_ttl(mut self, metric: impl Into<String>, hours: i64) -> Self {
+        self.metric_ttl_hours.insert(metric.into(), hours);
+        self
+    }
+
+    /// Override the cache TTL for a specific judge model
+    pub fn with_model_ttl(mut self, model: impl Into<String>, hours: i64) -> Self {
+        self.model_ttl_hours.insert(model.into(), hours);
+        self
+    }
+
+    /// Resolve the effective cache TTL for an evaluation, preferring a
+    /// per-metric override, then a per-model override, then the global
+    /// `cache_ttl_hours`.
+    fn resolve_ttl_hours(&self, metric: &str, model: &str) -> i64 {
+        self.metric_ttl_hours
+            .get(metric)
+            .or_else(|| self.model_ttl_hours.get(model))
+            .copied()
+            .unwrap_or(self.cache_ttl_hours)
+    }
+}
+
+/// A single token bucket used to throttle calls to one judge model.
+struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(settings: RateLimitSettings) -> Self {
+        Self {
+            rate: settings.rate,
+            burst: settings.burst,
+            tokens: settings.burst,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Refill based on elapsed time, then return how long the caller must
+    /// wait (if any) before a token is available.
+    fn poll(&mut self) -> Option<std::time::Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else if self.rate <= 0.0 {
+            // A non-positive rate never refills the bucket; block forever
+            // rather than dividing by a non-positive rate, which could
+            // produce an infinite `Duration` and panic in `from_secs_f64`.
+            Some(std::time::Duration::MAX)
+        } else {
+            let deficit = 1.0 - self.tokens;
+            Some(std::time::Duration::from_secs_f64(deficit / self.rate))
+        }
+    }
+}
+
+/// Rate limiter managing one token bucket per judge model.
+struct JudgeRateLimiter {
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+    settings: HashMap<String, RateLimitSettings>,
+}
+
+impl JudgeRateLimiter {
+    fn new(settings: HashMap<String, RateLimitSettings>) -> Self {
+        Self {
+            buckets: Mutex::new(HashMap::new()),
+            settings,
+        }
+    }
+
+    /// Acquire a token for `model`, waiting if the bucket is currently empty.
+    /// Models with no configured rate limit return immediately.
+    async fn acquire(&self, model: &str) {
+        let Some(settings) = self.settings.get(model) else {
+            return;
+        };
+
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().unwrap();
+                let bucket = buckets
+                    .entry(model.to_string())
+                    .or_insert_with(|| TokenBucket::new(*settings));
+                bucket.poll()
+            };
+
+            match wait {
+                None => return,
+                Some(duration) => tokio::time::sleep(duration).await,
+            }
+        }
+    }
 }
 
 /// A cached evaluation result
@@ -150,13 +300,16 @@ struct CachedResult {
 
     /// Cost of this evaluation
     cost: f64,
+
+    /// TTL in hours that was in effect when this entry was cached
+    ttl_hours: i64,
 }
 
 impl CachedResult {
-    /// Check if this cached result is still valid
-    fn is_valid(&self, ttl_hours: i64) -> bool {
+    /// Check if this cached result is still valid against its own TTL
+    fn is_valid(&self) -> bool {
         let age = Utc::now().signed_duration_since(self.cached_at);
-        age < Duration::hours(ttl_hours)
+        age < Duration::hours(self.ttl_hours)
     }
 }
 
@@ -212,13 +365,14 @@ impl EvaluationCache {
         }
     }
 
-    /// Get a cached result
-    pub fn get(&self, key: &CacheKey, ttl_hours: i64) -> Option<(String, f64)> {
+    /// Get a cached result, checking validity against the TTL stored with
+    /// the entry itself (see `EvaluationCache::put`)
+    pub fn get(&self, key: &CacheKey) -> Option<(String, f64)> {
         let hash = key.fast_hash();
         let mut cache = self.cache.lock().unwrap();
 
         if let Some(cached) = cache.get(&hash) {
-            if cached.is_valid(ttl_hours) {
+            if cached.is_valid() {
                 *self.hits.lock().unwrap() += 1;
                 return Some((cached.result.clone(), cached.cost));
             } else {
@@ -231,13 +385,14 @@ impl EvaluationCache {
         None
     }
 
-    /// Put a result into the cache
-    pub fn put(&self, key: CacheKey, result: String, cost: f64) {
+    /// Put a result into the cache with the TTL that applies to this entry
+    pub fn put(&self, key: CacheKey, result: String, cost: f64, ttl_hours: i64) {
         let hash = key.fast_hash();
         let cached = CachedResult {
             result,
             cached_at: Utc::now(),
             cost,
+            ttl_hours,
         };
 
         let mut cache = self.cache.lock().unwrap();
@@ -327,6 +482,9 @@ pub struct LLMJudge {
 
     /// Total cost across all evaluations
     total_cost: Arc<Mutex<f64>>,
+
+    /// Per-model token-bucket rate limiter
+    rate_limiter: JudgeRateLimiter,
 }
 
 impl LLMJudge {
@@ -337,12 +495,14 @@ impl LLMJudge {
         } else {
             None
         };
+        let rate_limiter = JudgeRateLimiter::new(config.rate_limits.clone());
 
         Self {
             provider,
             config,
             cache,
             total_cost: Arc::new(Mutex::new(0.0)),
+            rate_limiter,
         }
     }
 
@@ -375,7 +535,7 @@ impl LLMJudge {
                 model: self.config.model.clone(),
             };
 
-            if let Some((cached_result, cost)) = cache.get(&key, self.config.cache_ttl_hours) {
+            if let Some((cached_result, cost)) = cache.get(&key) {
                 tracing::debug!("Cache hit for {} evaluation", metric);
                 return self.parse_evaluation_result(&cached_result, cost);
             }
@@ -384,6 +544,9 @@ impl LLMJudge {
         // Build the evaluation prompt
         let eval_prompt = self.build_evaluation_prompt(prompt, response, rubric);
 
+        // Throttle outbound calls to this judge model, if configured
+        self.rate_limiter.acquire(&self.config.model).await;
+
         // Call the judge model
         let request = CompletionRequest::new(&self.config.model, eval_prompt)
             .with_temperature(self.config.temperature)
@@ -416,7 +579,8 @@ impl LLMJudge {
                 rubric: rubric.to_string(),
                 model: self.config.model.clone(),
             };
-            cache.put(key, judge_response.content.clone(), cost);
+            let ttl_hours = self.config.resolve_ttl_hours(metric, &self.config.model);
+            cache.put(key, judge_response.content.clone(), cost, ttl_hours);
         }
 
         // Parse and return the result
@@ -796,21 +960,29 @@ mod tests {
     async fn test_cache_ttl_expiration() {
         let cache = EvaluationCache::new(100);
 
-        let key = CacheKey {
+        let long_ttl_key = CacheKey {
             prompt: "test".to_string(),
             response: "test".to_string(),
-            metric: "test".to_string(),
+            metric: "stable-metric".to_string(),
+            rubric: "test".to_string(),
+            model: "gpt-4".to_string(),
+        };
+        let short_ttl_key = CacheKey {
+            prompt: "test".to_string(),
+            response: "test".to_string(),
+            metric: "volatile-metric".to_string(),
             rubric: "test".to_string(),
             model: "gpt-4".to_string(),
         };
 
-        cache.put(key.clone(), "result".to_string(), 0.01);
+        cache.put(long_ttl_key.clone(), "result".to_string(), 0.01, 168);
+        cache.put(short_ttl_key.clone(), "result".to_string(), 0.01, 0);
 
-        // Should be valid with long TTL
-        assert!(cache.get(&key, 168).is_some());
+        // Entry cached with a long TTL should still be valid
+        assert!(cache.get(&long_ttl_key).is_some());
 
-        // Should be invalid with 0 TTL (expired)
-        assert!(cache.get(&key, 0).is_none());
+        // Entry cached with a 0-hour TTL should already be expired
+        assert!(cache.get(&short_ttl_key).is_none());
     }
 
     #[test]
@@ -825,7 +997,7 @@ mod tests {
             model: "gpt-4".to_string(),
         };
 
-        cache.put(key.clone(), "result".to_string(), 0.01);
+        cache.put(key.clone(), "result".to_string(), 0.01, 168);
         assert_eq!(cache.stats().size, 1);
 
         cache.clear();
@@ -866,6 +1038,71 @@ mod tests {
         assert_eq!(key1.fast_hash(), key2.fast_hash());
     }
 
+    #[tokio::test]
+    async fn test_rate_limit_independent_per_model() {
+        let mut settings = HashMap::new();
+        settings.insert("gpt-4".to_string(), RateLimitSettings { rate: 1000.0, burst: 1.0 });
+        let limiter = JudgeRateLimiter::new(settings);
+
+        // gpt-4 has a bucket and is throttled after its burst is exhausted.
+        limiter.acquire("gpt-4").await;
+
+        // gpt-3.5-turbo has no configured limit, so it should never wait.
+        let start = Instant::now();
+        limiter.acquire("gpt-3.5-turbo").await;
+        limiter.acquire("gpt-3.5-turbo").await;
+        assert!(start.elapsed() < std::time::Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_throttles_burst() {
+        let mut settings = HashMap::new();
+        settings.insert("gpt-4".to_string(), RateLimitSettings { rate: 100.0, burst: 1.0 });
+        let limiter = JudgeRateLimiter::new(settings);
+
+        limiter.acquire("gpt-4").await; // consumes the only burst token
+        let start = Instant::now();
+        limiter.acquire("gpt-4").await; // must wait ~1/rate seconds for a refill
+        assert!(start.elapsed() >= std::time::Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_token_bucket_zero_rate_blocks_instead_of_panicking() {
+        let mut bucket = TokenBucket::new(RateLimitSettings { rate: 0.0, burst: 1.0 });
+
+        // Consumes the only burst token.
+        assert_eq!(bucket.poll(), None);
+
+        // A zero rate never refills; this must not panic in `from_secs_f64`.
+        assert_eq!(bucket.poll(), Some(std::time::Duration::MAX));
+    }
+
+    #[test]
+    fn test_resolve_ttl_hours_precedence() {
+        let config = JudgeConfig::new("gpt-4")
+            .with_cache_ttl_hours(168)
+            .with_model_ttl("gpt-4", 24)
+            .with_metric_ttl("rubric-grade", 720);
+
+        // Metric override wins over model and global
+        assert_eq!(config.resolve_ttl_hours("rubric-grade", "gpt-4"), 720);
+
+        // No metric override: falls back to model override
+        assert_eq!(config.resolve_ttl_hours("other-metric", "gpt-4"), 24);
+
+        // No metric or model override: falls back to global
+        assert_eq!(config.resolve_ttl_hours("other-metric", "gpt-3.5-turbo"), 168);
+    }
+
+    #[test]
+    fn test_judge_config_with_rate_limit() {
+        let config = JudgeConfig::new("gpt-4").with_rate_limit("gpt-4", 2.0, 5.0);
+
+        let settings = config.rate_limits.get("gpt-4").unwrap();
+        assert_eq!(settings.rate, 2.0);
+        assert_eq!(settings.burst, 5.0);
+    }
+
     #[test]
     fn test_cache_key_hash_differentiation() {
         let key1 = CacheKey {
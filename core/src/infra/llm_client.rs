@@ -36,11 +36,112 @@ pub use infra_llm_client::google;
 #[cfg(feature = "azure")]
 pub use infra_llm_client::azure;
 
-use std::time::Duration;
+use super::tracing::{attributes, spans};
+use std::time::{Duration, Instant};
+use tracing::Instrument;
 
 /// Provider types for client creation (mirrors rate_limit::ProviderType)
 pub type ProviderType = Provider;
 
+bitflags::bitflags! {
+    /// Capabilities a model may support, used to pick a capable model for a
+    /// request rather than hard-coding one. `ProviderConfig` is a foreign
+    /// type from `infra_llm_client`, so these are resolved against a local
+    /// table of known models (see [`resolve_capable_model`]) rather than
+    /// stored on it directly.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Capabilities: u8 {
+        /// Streaming completions (`stream()`/`InvokeModelWithResponseStream`-style APIs).
+        const STREAMING = 1 << 0;
+        /// Native function/tool calling.
+        const FUNCTION_CALLING = 1 << 1;
+        /// Image/vision input.
+        const VISION = 1 << 2;
+        /// JSON-mode / structured output.
+        const JSON_MODE = 1 << 3;
+    }
+}
+
+/// A request paired with the capabilities its target model must support.
+/// Built via [`RequireCapabilities::require`].
+pub struct CapableRequest {
+    request: LlmRequest,
+    required: Capabilities,
+}
+
+/// Extension trait adding a capability requirement to an `LlmRequest`.
+/// `LlmRequest` is a foreign type, so this is implemented as an extension
+/// trait rather than an inherent method.
+pub trait RequireCapabilities {
+    /// Mark this request as requiring `capabilities` from whichever model
+    /// serves it. Use with [`create_client_for_capabilities`] or
+    /// `ClientFactory::create_with_capabilities` to resolve a capable model.
+    fn require(self, capabilities: Capabilities) -> CapableRequest;
+}
+
+impl RequireCapabilities for LlmRequest {
+    fn require(self, capabilities: Capabilities) -> CapableRequest {
+        CapableRequest {
+            request: self,
+            required: capabilities,
+        }
+    }
+}
+
+/// Known capabilities for a provider's models, used to resolve a capable
+/// model for a [`CapableRequest`]. Not exhaustive - covers the models this
+/// crate's providers already target.
+fn known_model_capabilities(provider: Provider) -> &'static [(&'static str, Capabilities)] {
+    match provider {
+        Provider::OpenAI => &[
+            ("gpt-4-turbo", Capabilities::from_bits_truncate(0b1111)),
+            ("gpt-4", Capabilities::from_bits_truncate(0b0111)),
+            ("gpt-3.5-turbo", Capabilities::from_bits_truncate(0b0011)),
+        ],
+        Provider::Anthropic => &[
+            ("claude-3-opus-20240229", Capabilities::from_bits_truncate(0b1111)),
+            ("claude-3-sonnet-20240229", Capabilities::from_bits_truncate(0b1111)),
+            ("claude-3-haiku-20240307", Capabilities::from_bits_truncate(0b0011)),
+        ],
+        _ => &[],
+    }
+}
+
+/// Pick the first known model for `provider` whose capabilities are a
+/// superset of `required`, erroring if none qualifies.
+pub fn resolve_capable_model(provider: Provider, required: Capabilities) -> Result<&'static str, LlmError> {
+    known_model_capabilities(provider)
+        .iter()
+        .find(|(_, caps)| caps.contains(required))
+        .map(|(model, _)| *model)
+        .ok_or_else(|| {
+            LlmError::config(format!(
+                "no known model for provider {:?} supports the required capabilities ({:?})",
+                provider, required
+            ))
+        })
+}
+
+/// Create a client for `provider`, selecting a model that satisfies
+/// `capable.required` and erroring if no known model for the provider
+/// supports them. Returns the built client alongside the resolved request
+/// (with its model set).
+pub fn create_client_for_capabilities(
+    provider: Provider,
+    api_key: impl Into<String>,
+    capable: CapableRequest,
+) -> Result<(LlmClient, LlmRequest), LlmError> {
+    let model = resolve_capable_model(provider, capable.required)?;
+    let client = LlmClient::builder()
+        .provider(provider)
+        .api_key(api_key)
+        .default_model(model)
+        .with_cache()
+        .with_rate_limit()
+        .build()?;
+    Ok((client, capable.request.model(model)))
+}
+
 /// Create a client for a specific provider.
 pub fn create_client(provider: Provider, api_key: impl Into<String>) -> Result<LlmClient, LlmError> {
     LlmClient::builder()
@@ -58,14 +159,35 @@ pub fn create_client_with_config(
     model: impl Into<String>,
     timeout: Duration,
 ) -> Result<LlmClient, LlmError> {
-    LlmClient::builder()
+    create_client_with_config_ext(provider, api_key, model, timeout, None, None)
+}
+
+/// Create a client with custom configuration, including a proxy URL and/or a
+/// connect timeout distinct from the overall request `timeout`. Prefer
+/// `ClientFactory` when creating several clients that should share this
+/// configuration.
+pub fn create_client_with_config_ext(
+    provider: Provider,
+    api_key: impl Into<String>,
+    model: impl Into<String>,
+    timeout: Duration,
+    connect_timeout: Option<Duration>,
+    proxy: Option<String>,
+) -> Result<LlmClient, LlmError> {
+    let mut builder = LlmClient::builder()
         .provider(provider)
         .api_key(api_key)
         .default_model(model)
-        .timeout(timeout)
-        .with_cache()
-        .with_rate_limit()
-        .build()
+        .timeout(timeout);
+
+    if let Some(connect_timeout) = connect_timeout {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+    if let Some(proxy) = proxy {
+        builder = builder.proxy(proxy);
+    }
+
+    builder.with_cache().with_rate_limit().build()
 }
 
 /// Create a minimal client without caching or rate limiting.
@@ -80,6 +202,197 @@ pub fn create_basic_client(
         .build()
 }
 
+/// Wraps an `LlmClient` and emits a `spans::PROVIDER_CALL` span with the
+/// `attributes` constants around every `complete`/`stream` call, so callers
+/// get per-request observability without wiring tracing by hand.
+pub struct TracedLlmClient {
+    inner: LlmClient,
+    provider: Provider,
+}
+
+impl TracedLlmClient {
+    /// Wrap an existing client. `provider` is recorded on every span as the
+    /// `attributes::PROVIDER` attribute.
+    pub fn new(inner: LlmClient, provider: Provider) -> Self {
+        Self { inner, provider }
+    }
+
+    /// Complete a request, recording provider/model/token/latency/cost
+    /// attributes on the `spans::PROVIDER_CALL` span.
+    pub async fn complete(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
+        let model = request.model().unwrap_or("default").to_string();
+        let span = tracing::info_span!(
+            spans::PROVIDER_CALL,
+            "llm.provider" = ?self.provider,
+            "llm.model" = %model,
+            "llm.tokens.input" = tracing::field::Empty,
+            "llm.tokens.output" = tracing::field::Empty,
+            "llm.latency_ms" = tracing::field::Empty,
+            "llm.cost_usd" = tracing::field::Empty,
+        );
+
+        async move {
+            let start = Instant::now();
+            let result = self.inner.complete(request).await;
+            let latency_ms = start.elapsed().as_millis() as u64;
+            let span = tracing::Span::current();
+            span.record(attributes::LATENCY_MS, latency_ms);
+
+            match &result {
+                Ok(response) => {
+                    span.record(attributes::INPUT_TOKENS, response.usage.prompt_tokens);
+                    span.record(attributes::OUTPUT_TOKENS, response.usage.completion_tokens);
+                    span.record(attributes::COST_USD, estimate_cost(&model, &response.usage));
+                }
+                Err(error) => {
+                    tracing::error!(error = %error, "provider call failed");
+                }
+            }
+
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Stream a request, recording provider/model/latency attributes on the
+    /// `spans::PROVIDER_CALL` span. Per-chunk usage isn't known until the
+    /// stream is fully drained, so token/cost attributes aren't recorded here.
+    pub async fn stream(&self, request: LlmRequest) -> Result<impl futures::Stream<Item = Result<String, LlmError>>, LlmError> {
+        let model = request.model().unwrap_or("default").to_string();
+        let span = tracing::info_span!(
+            spans::PROVIDER_CALL,
+            "llm.provider" = ?self.provider,
+            "llm.model" = %model,
+            "llm.latency_ms" = tracing::field::Empty,
+        );
+
+        async move {
+            let start = Instant::now();
+            let result = self.inner.stream(request).await;
+            tracing::Span::current().record(attributes::LATENCY_MS, start.elapsed().as_millis() as u64);
+
+            if let Err(ref error) = result {
+                tracing::error!(error = %error, "provider stream call failed");
+            }
+
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// The wrapped client's provider.
+    pub fn provider(&self) -> Provider {
+        self.provider
+    }
+}
+
+/// Rough per-call cost estimate used for the `attributes::COST_USD` span
+/// attribute. Mirrors the pricing tables used elsewhere in this crate.
+fn estimate_cost(model: &str, usage: &Usage) -> f64 {
+    let (prompt_cost_per_1k, completion_cost_per_1k) = match model {
+        "gpt-4" | "gpt-4-0613" => (0.03, 0.06),
+        "gpt-4-turbo" | "gpt-4-turbo-preview" => (0.01, 0.03),
+        "gpt-3.5-turbo" | "gpt-3.5-turbo-0125" => (0.0005, 0.0015),
+        "claude-3-opus-20240229" => (0.015, 0.075),
+        "claude-3-sonnet-20240229" => (0.003, 0.015),
+        "claude-3-haiku-20240307" => (0.00025, 0.00125),
+        _ => (0.01, 0.03),
+    };
+
+    (usage.prompt_tokens as f64 / 1000.0) * prompt_cost_per_1k
+        + (usage.completion_tokens as f64 / 1000.0) * completion_cost_per_1k
+}
+
+/// Create a traced client for a specific provider. Behaves like
+/// `create_client`, but every call is wrapped in a `spans::PROVIDER_CALL`
+/// span carrying the `attributes` constants.
+pub fn create_traced_client(provider: Provider, api_key: impl Into<String>) -> Result<TracedLlmClient, LlmError> {
+    let inner = create_client(provider, api_key)?;
+    Ok(TracedLlmClient::new(inner, provider))
+}
+
+/// A composite client built from a pool of providers. Tries each client in
+/// the order it was registered, falling through to the next on failure, and
+/// records which provider actually served the request as the
+/// `attributes::PROVIDER` attribute on a `spans::PROVIDER_CALL` span.
+///
+/// Built via `ClientFactory::create_pool`.
+pub struct ClientPool {
+    clients: Vec<(Provider, LlmClient)>,
+}
+
+impl ClientPool {
+    /// Complete a request, trying each client in order until one succeeds.
+    /// If every client fails, the last error is returned.
+    pub async fn complete(&self, request: LlmRequest) -> Result<LlmResponse, LlmError> {
+        let span = tracing::info_span!(
+            spans::PROVIDER_CALL,
+            "llm.provider" = tracing::field::Empty,
+        );
+
+        async move {
+            let mut last_error = None;
+
+            for (provider, client) in &self.clients {
+                match client.complete(request.clone()).await {
+                    Ok(response) => {
+                        tracing::Span::current().record(attributes::PROVIDER, format!("{:?}", provider));
+                        return Ok(response);
+                    }
+                    Err(error) => {
+                        tracing::warn!(provider = ?provider, error = %error, "pool member failed, trying next");
+                        last_error = Some(error);
+                    }
+                }
+            }
+
+            Err(last_error
+                .unwrap_or_else(|| LlmError::config("ClientPool has no clients configured".to_string())))
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Stream a request, trying each client in order until one starts
+    /// streaming successfully. If every client fails, the last error is
+    /// returned.
+    pub async fn stream(&self, request: LlmRequest) -> Result<impl futures::Stream<Item = Result<String, LlmError>>, LlmError> {
+        let span = tracing::info_span!(
+            spans::PROVIDER_CALL,
+            "llm.provider" = tracing::field::Empty,
+        );
+
+        async move {
+            let mut last_error = None;
+
+            for (provider, client) in &self.clients {
+                match client.stream(request.clone()).await {
+                    Ok(stream) => {
+                        tracing::Span::current().record(attributes::PROVIDER, format!("{:?}", provider));
+                        return Ok(stream);
+                    }
+                    Err(error) => {
+                        tracing::warn!(provider = ?provider, error = %error, "pool member failed to start stream, trying next");
+                        last_error = Some(error);
+                    }
+                }
+            }
+
+            Err(last_error
+                .unwrap_or_else(|| LlmError::config("ClientPool has no clients configured".to_string())))
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// The providers in this pool, in fallback order.
+    pub fn providers(&self) -> Vec<Provider> {
+        self.clients.iter().map(|(provider, _)| *provider).collect()
+    }
+}
+
 /// Get the API key from environment for a provider.
 pub fn get_api_key_from_env(provider: Provider) -> Option<String> {
     std::env::var(provider.api_key_env()).ok()
@@ -93,18 +406,26 @@ pub fn has_api_key(provider: Provider) -> bool {
 /// Builder for creating multiple clients with shared configuration.
 pub struct ClientFactory {
     timeout: Duration,
+    connect_timeout: Option<Duration>,
     max_retries: u32,
     enable_cache: bool,
     enable_rate_limit: bool,
+    proxy: Option<String>,
+    base_url: Option<String>,
+    chat_endpoint: Option<String>,
 }
 
 impl Default for ClientFactory {
     fn default() -> Self {
         Self {
             timeout: DEFAULT_TIMEOUT,
+            connect_timeout: None,
             max_retries: DEFAULT_MAX_RETRIES,
             enable_cache: true,
             enable_rate_limit: true,
+            proxy: None,
+            base_url: None,
+            chat_endpoint: None,
         }
     }
 }
@@ -121,6 +442,14 @@ impl ClientFactory {
         self
     }
 
+    /// Set the TCP connect timeout, distinct from the overall request
+    /// `timeout`. Useful for bounding how long a client waits to establish a
+    /// connection to a slow or unreachable endpoint.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
     /// Set the maximum retry attempts.
     pub fn max_retries(mut self, retries: u32) -> Self {
         self.max_retries = retries;
@@ -139,6 +468,44 @@ impl ClientFactory {
         self
     }
 
+    /// Route traffic through an HTTP/SOCKS5 proxy (e.g. `socks5://127.0.0.1:1080`
+    /// or `https://proxy.example.com:8443`).
+    ///
+    /// If no proxy is set explicitly, `create`/`create_from_env` fall back to
+    /// the `HTTPS_PROXY`/`ALL_PROXY` environment variables.
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Override the provider's default base URL, for pointing at an
+    /// OpenAI-compatible self-hosted endpoint (LocalAI, vLLM's OpenAI
+    /// front-end, a gateway) instead of the provider's public API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Override the chat-completions path appended to `base_url`, for
+    /// OpenAI-compatible servers that don't serve it at the standard
+    /// `/v1/chat/completions` path.
+    pub fn chat_endpoint(mut self, chat_endpoint: impl Into<String>) -> Self {
+        self.chat_endpoint = Some(chat_endpoint.into());
+        self
+    }
+
+    /// Resolve the effective proxy URL: the explicitly configured proxy, or
+    /// else `HTTPS_PROXY`/`ALL_PROXY` from the environment.
+    fn resolve_proxy(&self) -> Option<String> {
+        self.proxy.clone().or_else(|| {
+            std::env::var("HTTPS_PROXY")
+                .or_else(|_| std::env::var("https_proxy"))
+                .or_else(|_| std::env::var("ALL_PROXY"))
+                .or_else(|_| std::env::var("all_proxy"))
+                .ok()
+        })
+    }
+
     /// Create a client for the specified provider.
     pub fn create(&self, provider: Provider, api_key: impl Into<String>) -> Result<LlmClient, LlmError> {
         let mut builder = LlmClient::builder()
@@ -147,14 +514,56 @@ impl ClientFactory {
             .timeout(self.timeout)
             .max_retries(self.max_retries);
 
+        builder = self.apply_shared_settings(builder);
+        builder.build()
+    }
+
+    /// Apply the connect-timeout/proxy/base-url/chat-endpoint/cache/
+    /// rate-limit settings shared by `create` and `create_with_capabilities`.
+    fn apply_shared_settings(&self, mut builder: LlmClientBuilder) -> LlmClientBuilder {
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = self.resolve_proxy() {
+            builder = builder.proxy(proxy);
+        }
+        if let Some(base_url) = &self.base_url {
+            builder = builder.base_url(base_url.clone());
+        }
+        if let Some(chat_endpoint) = &self.chat_endpoint {
+            builder = builder.chat_endpoint(chat_endpoint.clone());
+        }
         if self.enable_cache {
             builder = builder.with_cache();
         }
         if self.enable_rate_limit {
             builder = builder.with_rate_limit();
         }
+        builder
+    }
 
-        builder.build()
+    /// Create a client for `provider`, selecting a model that satisfies
+    /// `capable.required` and erroring if no known model for the provider
+    /// supports them. Returns the built client alongside the resolved
+    /// request (with its model set).
+    pub fn create_with_capabilities(
+        &self,
+        provider: Provider,
+        api_key: impl Into<String>,
+        capable: CapableRequest,
+    ) -> Result<(LlmClient, LlmRequest), LlmError> {
+        let model = resolve_capable_model(provider, capable.required)?;
+
+        let mut builder = LlmClient::builder()
+            .provider(provider)
+            .api_key(api_key)
+            .default_model(model)
+            .timeout(self.timeout)
+            .max_retries(self.max_retries);
+
+        builder = self.apply_shared_settings(builder);
+        let client = builder.build()?;
+        Ok((client, capable.request.model(model)))
     }
 
     /// Create a client using the API key from environment.
@@ -167,6 +576,19 @@ impl ClientFactory {
 
         self.create(provider, api_key)
     }
+
+    /// Build a composite client from `providers`, an ordered list of
+    /// `(provider, api_key)` pairs. The returned `ClientPool` tries each
+    /// client in order on failure and records which one actually served the
+    /// request via the `attributes::PROVIDER` span attribute.
+    pub fn create_pool(&self, providers: Vec<(Provider, String)>) -> Result<ClientPool, LlmError> {
+        let clients = providers
+            .into_iter()
+            .map(|(provider, api_key)| self.create(provider, api_key).map(|client| (provider, client)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ClientPool { clients })
+    }
 }
 
 /// Create a simple chat request.
@@ -226,4 +648,111 @@ mod tests {
         // Can't actually create a client without an API key
         assert!(factory.create_from_env(Provider::OpenAI).is_err());
     }
+
+    #[test]
+    fn test_resolve_capable_model_picks_qualifying_model() {
+        let model = resolve_capable_model(Provider::OpenAI, Capabilities::VISION).unwrap();
+        assert_eq!(model, "gpt-4-turbo");
+    }
+
+    #[test]
+    fn test_resolve_capable_model_errors_when_none_qualify() {
+        // gpt-4-turbo carries every named capability, so require a bit no known model has.
+        let result = resolve_capable_model(Provider::OpenAI, Capabilities::from_bits_retain(1 << 4));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_require_capabilities_resolves_model_on_request() {
+        let capable = chat("describe this image").require(Capabilities::VISION | Capabilities::STREAMING);
+        let (_client, request) = create_client_for_capabilities(Provider::Anthropic, "test-key", capable).unwrap();
+        assert_eq!(request.model(), Some("claude-3-opus-20240229"));
+    }
+
+    #[test]
+    fn test_client_factory_create_with_capabilities() {
+        let factory = ClientFactory::new();
+        let capable = chat("hello").require(Capabilities::JSON_MODE);
+        let (_client, request) = factory.create_with_capabilities(Provider::OpenAI, "test-key", capable).unwrap();
+        assert_eq!(request.model(), Some("gpt-4-turbo"));
+    }
+
+    #[test]
+    fn test_estimate_cost() {
+        let usage = Usage {
+            prompt_tokens: 1000,
+            completion_tokens: 1000,
+            total_tokens: 2000,
+        };
+
+        let cost = estimate_cost("gpt-4", &usage);
+        assert!((cost - 0.09).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_client_factory_proxy_and_connect_timeout() {
+        let factory = ClientFactory::new()
+            .proxy("socks5://127.0.0.1:1080")
+            .connect_timeout(Duration::from_secs(5));
+
+        assert_eq!(factory.proxy.as_deref(), Some("socks5://127.0.0.1:1080"));
+        assert_eq!(factory.connect_timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_client_factory_base_url_and_chat_endpoint() {
+        let factory = ClientFactory::new()
+            .base_url("http://localhost:8080/v1")
+            .chat_endpoint("/custom/chat");
+
+        assert_eq!(factory.base_url.as_deref(), Some("http://localhost:8080/v1"));
+        assert_eq!(factory.chat_endpoint.as_deref(), Some("/custom/chat"));
+    }
+
+    #[test]
+    fn test_client_factory_proxy_env_fallback() {
+        std::env::remove_var("HTTPS_PROXY");
+        std::env::remove_var("ALL_PROXY");
+        std::env::set_var("HTTPS_PROXY", "https://proxy.example.com:8443");
+
+        let factory = ClientFactory::new();
+        assert_eq!(
+            factory.resolve_proxy().as_deref(),
+            Some("https://proxy.example.com:8443")
+        );
+
+        std::env::remove_var("HTTPS_PROXY");
+    }
+
+    #[test]
+    fn test_client_factory_explicit_proxy_wins_over_env() {
+        std::env::set_var("HTTPS_PROXY", "https://env-proxy.example.com");
+
+        let factory = ClientFactory::new().proxy("https://explicit-proxy.example.com");
+        assert_eq!(
+            factory.resolve_proxy().as_deref(),
+            Some("https://explicit-proxy.example.com")
+        );
+
+        std::env::remove_var("HTTPS_PROXY");
+    }
+
+    #[test]
+    fn test_create_traced_client() {
+        let client = create_traced_client(Provider::OpenAI, "test-key").unwrap();
+        assert_eq!(client.provider(), Provider::OpenAI);
+    }
+
+    #[test]
+    fn test_create_pool_preserves_provider_order() {
+        let factory = ClientFactory::new();
+        let pool = factory
+            .create_pool(vec![
+                (Provider::OpenAI, "test-key".to_string()),
+                (Provider::Anthropic, "test-key".to_string()),
+            ])
+            .unwrap();
+
+        assert_eq!(pool.providers(), vec![Provider::OpenAI, Provider::Anthropic]);
+    }
 }
@@ -63,6 +63,102 @@ pub fn init_full(service_name: &str) -> InfraResult<()> {
     Ok(())
 }
 
+/// Environment variable used to override the OTLP collector endpoint,
+/// matching the standard OpenTelemetry SDK convention.
+pub const OTEL_EXPORTER_OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+/// Transport for the OTLP exporter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtlpProtocol {
+    /// OTLP over gRPC (the collector default, port 4317)
+    Grpc,
+    /// OTLP over HTTP with protobuf payloads (port 4318)
+    HttpProtobuf,
+    /// OTLP over HTTP with JSON payloads (port 4318)
+    HttpJson,
+}
+
+impl OtlpProtocol {
+    fn into_infra(self) -> infra_otel::OtlpProtocol {
+        match self {
+            OtlpProtocol::Grpc => infra_otel::OtlpProtocol::Grpc,
+            OtlpProtocol::HttpProtobuf => infra_otel::OtlpProtocol::HttpProtobuf,
+            OtlpProtocol::HttpJson => infra_otel::OtlpProtocol::HttpJson,
+        }
+    }
+}
+
+/// Initialize tracing with spans exported to an OTLP collector.
+///
+/// `endpoint` is used unless the standard `OTEL_EXPORTER_OTLP_ENDPOINT`
+/// environment variable is set, in which case the env var takes priority.
+/// Benchmarks run long and remote, so exporting `spans::BENCHMARK_RUN` spans
+/// to a real collector is essential for post-run analysis.
+pub fn init_with_otlp(
+    service_name: &str,
+    endpoint: impl Into<String>,
+    protocol: OtlpProtocol,
+) -> InfraResult<()> {
+    init_tracing(&otlp_config(service_name, endpoint, protocol, None, None))
+}
+
+/// Like `init_with_otlp`, but also sets the trace sampling ratio (0.0 - 1.0).
+pub fn init_with_otlp_sampled(
+    service_name: &str,
+    endpoint: impl Into<String>,
+    protocol: OtlpProtocol,
+    sampling_ratio: f64,
+) -> InfraResult<()> {
+    init_tracing(&otlp_config(
+        service_name,
+        endpoint,
+        protocol,
+        Some(sampling_ratio),
+        None,
+    ))
+}
+
+/// Initialize tracing exporting to a Jaeger-compatible collector endpoint
+/// (Jaeger's OTLP ingest port, typically `4317`/`4318`).
+pub fn init_with_jaeger(service_name: &str, jaeger_endpoint: impl Into<String>) -> InfraResult<()> {
+    init_tracing(&otlp_config(
+        service_name,
+        jaeger_endpoint.into(),
+        OtlpProtocol::Grpc,
+        None,
+        None,
+    ))
+}
+
+/// Build an `OtelConfig` wired to an OTLP exporter, honoring
+/// `OTEL_EXPORTER_OTLP_ENDPOINT` and an optional sampling ratio. Exposed for
+/// callers that need to layer on further customization before calling
+/// `init_tracing`/`init_metrics` themselves.
+pub fn otlp_config(
+    service_name: &str,
+    endpoint: impl Into<String>,
+    protocol: OtlpProtocol,
+    sampling_ratio: Option<f64>,
+    jaeger_endpoint: Option<String>,
+) -> OtelConfig {
+    let endpoint = std::env::var(OTEL_EXPORTER_OTLP_ENDPOINT_ENV).unwrap_or_else(|_| endpoint.into());
+
+    let mut builder = OtelConfig::builder()
+        .service_name(service_name)
+        .service_version(SERVICE_VERSION)
+        .otlp_endpoint(endpoint)
+        .otlp_protocol(protocol.into_infra());
+
+    if let Some(ratio) = sampling_ratio {
+        builder = builder.sampling_ratio(ratio);
+    }
+    if let Some(jaeger_endpoint) = jaeger_endpoint {
+        builder = builder.jaeger_endpoint(jaeger_endpoint);
+    }
+
+    builder.build()
+}
+
 /// Shutdown the tracing system
 ///
 /// Call this before your application exits to ensure all spans
@@ -138,4 +234,36 @@ mod tests {
         assert!(!attributes::MODEL.is_empty());
         assert!(!attributes::LATENCY_MS.is_empty());
     }
+
+    #[test]
+    fn test_otlp_config_uses_explicit_endpoint() {
+        std::env::remove_var(OTEL_EXPORTER_OTLP_ENDPOINT_ENV);
+
+        let config = otlp_config(
+            "test-service",
+            "http://localhost:4317",
+            OtlpProtocol::Grpc,
+            None,
+            None,
+        );
+
+        assert_eq!(config.service_name(), "test-service");
+    }
+
+    #[test]
+    fn test_otlp_config_env_var_overrides_endpoint() {
+        std::env::set_var(OTEL_EXPORTER_OTLP_ENDPOINT_ENV, "http://collector:4317");
+
+        let config = otlp_config(
+            "test-service",
+            "http://localhost:4317",
+            OtlpProtocol::Grpc,
+            Some(0.25),
+            None,
+        );
+
+        assert_eq!(config.service_name(), "test-service");
+
+        std::env::remove_var(OTEL_EXPORTER_OTLP_ENDPOINT_ENV);
+    }
 }
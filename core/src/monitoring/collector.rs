@@ -7,11 +7,13 @@
 //! Metric collection and aggregation.
 
 use anyhow::Result;
+use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::Arc;
 use std::collections::HashMap;
 use parking_lot::RwLock;
 use tokio::task::JoinHandle;
 use chrono::{DateTime, Utc, Duration};
+use sysinfo::{Pid, ProcessRefreshKind, RefreshKind, System};
 
 use crate::monitoring::{
     metrics::Metric,
@@ -25,6 +27,10 @@ pub struct CollectorConfig {
     pub retention_period: u64,
     /// Enable detailed metrics
     pub detailed_metrics: bool,
+    /// Sample and publish process/async-runtime self-metrics
+    pub process_metrics_enabled: bool,
+    /// How often self-metrics are sampled
+    pub process_metrics_interval_secs: u64,
 }
 
 impl Default for CollectorConfig {
@@ -32,10 +38,34 @@ impl Default for CollectorConfig {
         Self {
             retention_period: 3600, // 1 hour
             detailed_metrics: false,
+            process_metrics_enabled: false,
+            process_metrics_interval_secs: 15,
         }
     }
 }
 
+impl CollectorConfig {
+    /// Create a new collector configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable process/async-runtime self-metrics, sampled by their own task
+    /// every `process_metrics_interval_secs` (not on the cleanup interval),
+    /// so host resource pressure can be correlated with LLM latency on the
+    /// same dashboard
+    pub fn with_process_metrics(mut self, enabled: bool) -> Self {
+        self.process_metrics_enabled = enabled;
+        self
+    }
+
+    /// Set how often self-metrics are sampled
+    pub fn with_process_metrics_interval_secs(mut self, seconds: u64) -> Self {
+        self.process_metrics_interval_secs = seconds;
+        self
+    }
+}
+
 /// Time-series metric storage
 #[derive(Debug, Clone)]
 struct MetricSeries {
@@ -109,6 +139,10 @@ pub struct MetricCollector {
     metrics: Arc<RwLock<HashMap<String, MetricSeries>>>,
     provider_stats: Arc<RwLock<HashMap<String, ProviderStats>>>,
     cleanup_handle: Arc<RwLock<Option<JoinHandle<()>>>>,
+    self_metrics_handle: Arc<RwLock<Option<JoinHandle<()>>>>,
+    /// Depth of the provider request queue, reported by callers via
+    /// `set_queue_depth` and sampled alongside process/runtime metrics
+    queue_depth: Arc<AtomicI64>,
 }
 
 impl MetricCollector {
@@ -120,6 +154,8 @@ impl MetricCollector {
             metrics: Arc::new(RwLock::new(HashMap::new())),
             provider_stats: Arc::new(RwLock::new(HashMap::new())),
             cleanup_handle: Arc::new(RwLock::new(None)),
+            self_metrics_handle: Arc::new(RwLock::new(None)),
+            queue_depth: Arc::new(AtomicI64::new(0)),
         };
 
         // Subscribe to events
@@ -132,6 +168,12 @@ impl MetricCollector {
         collector
     }
 
+    /// Record the current depth of the provider request queue, so it can
+    /// be sampled alongside process/runtime self-metrics
+    pub fn set_queue_depth(&self, depth: i64) {
+        self.queue_depth.store(depth, Ordering::Relaxed);
+    }
+
     /// Start the metric collector
     pub async fn start(&self) -> Result<()> {
         tracing::info!("Starting metric collector");
@@ -151,6 +193,27 @@ impl MetricCollector {
         let mut handle = self.cleanup_handle.write();
         *handle = Some(cleanup);
 
+        if self.config.detailed_metrics || self.config.process_metrics_enabled {
+            let metrics = self.metrics.clone();
+            let queue_depth = self.queue_depth.clone();
+            let interval_secs = self.config.process_metrics_interval_secs.max(1);
+
+            let self_metrics = tokio::spawn(async move {
+                let pid = Pid::from_u32(std::process::id());
+                let mut system = System::new_with_specifics(
+                    RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
+                );
+                let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(interval_secs));
+                loop {
+                    interval.tick().await;
+                    Self::sample_self_metrics(&mut system, pid, &queue_depth, &metrics);
+                }
+            });
+
+            let mut handle = self.self_metrics_handle.write();
+            *handle = Some(self_metrics);
+        }
+
         Ok(())
     }
 
@@ -160,9 +223,54 @@ impl MetricCollector {
         if let Some(h) = handle.take() {
             h.abort();
         }
+
+        if let Some(h) = self.self_metrics_handle.write().take() {
+            h.abort();
+        }
+
         Ok(())
     }
 
+    /// Sample process and async-runtime self-metrics and record them as
+    /// gauges under the `process_*` / `runtime_*` namespace
+    fn sample_self_metrics(
+        system: &mut System,
+        pid: Pid,
+        queue_depth: &AtomicI64,
+        metrics: &RwLock<HashMap<String, MetricSeries>>,
+    ) {
+        system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), true);
+        let now = Utc::now();
+        let mut metrics = metrics.write();
+
+        if let Some(process) = system.process(pid) {
+            Self::record_self_metric(&mut metrics, "process_resident_memory_bytes", now, (process.memory() * 1024) as f64);
+            Self::record_self_metric(&mut metrics, "process_virtual_memory_bytes", now, (process.virtual_memory() * 1024) as f64);
+            Self::record_self_metric(&mut metrics, "process_cpu_usage_percent", now, process.cpu_usage() as f64);
+        }
+
+        if let Some(open_fds) = open_fd_count() {
+            Self::record_self_metric(&mut metrics, "process_open_fds", now, open_fds as f64);
+        }
+
+        let runtime_metrics = tokio::runtime::Handle::current().metrics();
+        Self::record_self_metric(&mut metrics, "runtime_worker_threads", now, runtime_metrics.num_workers() as f64);
+        Self::record_self_metric(&mut metrics, "runtime_live_tasks", now, runtime_metrics.num_alive_tasks() as f64);
+        Self::record_self_metric(&mut metrics, "runtime_queue_depth", now, queue_depth.load(Ordering::Relaxed) as f64);
+    }
+
+    fn record_self_metric(
+        metrics: &mut HashMap<String, MetricSeries>,
+        name: &str,
+        timestamp: DateTime<Utc>,
+        value: f64,
+    ) {
+        let series = metrics
+            .entry(name.to_string())
+            .or_insert_with(|| MetricSeries::new(name.to_string(), HashMap::new()));
+        series.add_point(timestamp, value);
+    }
+
     /// Cleanup old metrics
     fn cleanup_old_metrics(metrics: &RwLock<HashMap<String, MetricSeries>>, retention_period: u64) {
         let cutoff = Utc::now() - Duration::seconds(retention_period as i64);
@@ -232,6 +340,20 @@ impl MetricCollector {
     }
 }
 
+/// Count this process's open file descriptors via `/proc/self/fd`. Returns
+/// `None` off Linux (or if `/proc` can't be read), matching this module's
+/// graceful-degradation approach to platform-specific introspection.
+fn open_fd_count() -> Option<usize> {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_dir("/proc/self/fd").ok().map(|entries| entries.count())
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
 /// Event subscriber for metric collection
 struct CollectorSubscriber {
     metrics: Arc<RwLock<HashMap<String, MetricSeries>>>,
@@ -370,4 +492,32 @@ mod tests {
         assert_eq!(stats.total_requests, 100);
         assert_eq!(stats.successful_requests, 95);
     }
+
+    #[test]
+    fn test_collector_config_process_metrics_builder() {
+        let config = CollectorConfig::new()
+            .with_process_metrics(true)
+            .with_process_metrics_interval_secs(5);
+
+        assert!(config.process_metrics_enabled);
+        assert_eq!(config.process_metrics_interval_secs, 5);
+    }
+
+    #[tokio::test]
+    async fn test_self_metrics_sampled_when_enabled() {
+        let config = CollectorConfig::new()
+            .with_process_metrics(true)
+            .with_process_metrics_interval_secs(1);
+        let event_bus = Arc::new(EventBus::new());
+        let collector = MetricCollector::new(config, event_bus);
+
+        collector.set_queue_depth(3);
+        collector.start().await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(1200)).await;
+        collector.stop().await.unwrap();
+
+        let metrics = collector.get_metrics().await;
+        assert!(metrics.iter().any(|m| m.name == "runtime_worker_threads"));
+        assert!(metrics.iter().any(|m| m.name == "runtime_queue_depth"));
+    }
 }
@@ -273,9 +273,45 @@ impl MonitoringEvent {
         )
     }
 
-    /// Create a latency event
-    pub fn latency(provider: &str, latency: f64) -> Self {
-        let metric = LatencyMetric::new(provider, "", latency);
+    /// Create a latency event, optionally carrying a request/trace ID so
+    /// the resulting histogram observation can attach an exemplar
+    pub fn latency(provider: &str, latency: f64, trace_id: Option<&str>) -> Self {
+        let mut metric = LatencyMetric::new(provider, "", latency);
+        if let Some(trace_id) = trace_id {
+            metric = metric.with_trace_id(trace_id);
+        }
+        Self::new(
+            EventType::MetricRecorded,
+            EventPayload::Metric(MetricEvent {
+                metric: metric.to_metric(),
+            }),
+        )
+    }
+
+    /// Create a time-to-first-token event for a streaming request,
+    /// optionally carrying a request/trace ID so the resulting histogram
+    /// observation can attach an exemplar
+    pub fn time_to_first_token(provider: &str, ttft: f64, trace_id: Option<&str>) -> Self {
+        let mut metric = TimeToFirstTokenMetric::new(provider, "", ttft);
+        if let Some(trace_id) = trace_id {
+            metric = metric.with_trace_id(trace_id);
+        }
+        Self::new(
+            EventType::MetricRecorded,
+            EventPayload::Metric(MetricEvent {
+                metric: metric.to_metric(),
+            }),
+        )
+    }
+
+    /// Create a stream-duration event for a completed streaming request,
+    /// optionally carrying a request/trace ID so the resulting histogram
+    /// observation can attach an exemplar
+    pub fn stream_duration(provider: &str, duration: f64, trace_id: Option<&str>) -> Self {
+        let mut metric = StreamDurationMetric::new(provider, "", duration);
+        if let Some(trace_id) = trace_id {
+            metric = metric.with_trace_id(trace_id);
+        }
         Self::new(
             EventType::MetricRecorded,
             EventPayload::Metric(MetricEvent {
@@ -295,9 +331,13 @@ impl MonitoringEvent {
         )
     }
 
-    /// Create a cost event
-    pub fn cost(provider: &str, cost: f64) -> Self {
-        let metric = CostMetric::new(provider, "", cost);
+    /// Create a cost event, optionally carrying a request/trace ID so the
+    /// resulting histogram observation can attach an exemplar
+    pub fn cost(provider: &str, cost: f64, trace_id: Option<&str>) -> Self {
+        let mut metric = CostMetric::new(provider, "", cost);
+        if let Some(trace_id) = trace_id {
+            metric = metric.with_trace_id(trace_id);
+        }
         Self::new(
             EventType::MetricRecorded,
             EventPayload::Metric(MetricEvent {
@@ -306,6 +346,19 @@ impl MonitoringEvent {
         )
     }
 
+    /// Create a cumulative cost-total event, published alongside [`Self::cost`]'s
+    /// per-request histogram so `rate()`/`increase()` queries against total
+    /// spend keep working
+    pub fn cost_total(provider: &str, cost: f64) -> Self {
+        let metric = CostMetric::new(provider, "", cost);
+        Self::new(
+            EventType::MetricRecorded,
+            EventPayload::Metric(MetricEvent {
+                metric: metric.to_counter_metric(),
+            }),
+        )
+    }
+
     /// Create an error event
     pub fn error(provider: &str, error_type: &str) -> Self {
         let metric = ErrorMetric::new(provider, error_type);
@@ -6,10 +6,13 @@
 
 //! Integration helpers for monitoring LLM providers and benchmarks.
 
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
 use std::time::Instant;
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::Stream;
 
 use crate::providers::{Provider, ProviderError, CompletionRequest, CompletionResponse, ResponseStream, ModelInfo};
 use crate::monitoring::{
@@ -58,7 +61,7 @@ impl Provider for MonitoredProvider {
         match result {
             Ok(ref response) => {
                 // Record successful request
-                self.monitoring.record_latency(provider_name, latency);
+                self.monitoring.record_latency(provider_name, latency, Some(&request_id));
 
                 // Record token usage
                 self.monitoring.record_tokens(
@@ -69,7 +72,7 @@ impl Provider for MonitoredProvider {
 
                 // Estimate cost (simplified - should use actual pricing)
                 let cost = Self::estimate_cost(&model, response.usage.prompt_tokens, response.usage.completion_tokens);
-                self.monitoring.record_cost(provider_name, cost);
+                self.monitoring.record_cost(provider_name, cost, Some(&request_id));
 
                 // Emit detailed event
                 let event = MonitoringEvent::new(
@@ -92,15 +95,7 @@ impl Provider for MonitoredProvider {
             }
             Err(ref e) => {
                 // Record error
-                let error_type = match e {
-                    ProviderError::RateLimitExceeded(_) => "rate_limit",
-                    ProviderError::AuthenticationError(_) => "auth_error",
-                    ProviderError::InvalidRequest(_) => "invalid_request",
-                    ProviderError::NetworkError(_) => "network_error",
-                    _ => "unknown_error",
-                };
-
-                self.monitoring.record_error(provider_name, error_type);
+                self.monitoring.record_error(provider_name, Self::classify_error(e));
 
                 // Emit error event
                 let event = MonitoringEvent::new(
@@ -123,9 +118,29 @@ impl Provider for MonitoredProvider {
     }
 
     async fn stream(&self, request: CompletionRequest) -> Result<ResponseStream, ProviderError> {
-        // For streaming, we record start but can't capture full metrics until stream completes
-        self.monitoring.record_request(self.inner.name(), &request.model);
-        self.inner.stream(request).await
+        let provider_name = self.inner.name().to_string();
+        let request_id = Self::generate_request_id();
+
+        self.monitoring.record_request(&provider_name, &request.model);
+
+        let start = Instant::now();
+        let inner = match self.inner.stream(request).await {
+            Ok(inner) => inner,
+            Err(e) => {
+                self.monitoring.record_error(&provider_name, Self::classify_error(&e));
+                return Err(e);
+            }
+        };
+
+        Ok(Box::pin(InstrumentedStream {
+            inner,
+            start,
+            first_token_recorded: false,
+            completed: false,
+            monitoring: self.monitoring.clone(),
+            provider: provider_name,
+            trace_id: request_id,
+        }))
     }
 
     fn supported_models(&self) -> Vec<ModelInfo> {
@@ -150,6 +165,23 @@ impl Provider for MonitoredProvider {
 }
 
 impl MonitoredProvider {
+    /// Classify a provider error into a stable `error_type` label for metrics
+    fn classify_error(error: &ProviderError) -> &'static str {
+        match error {
+            ProviderError::RateLimitExceeded { .. } => "rate_limit",
+            ProviderError::InvalidApiKey => "invalid_key",
+            ProviderError::AuthenticationError(_) => "auth_error",
+            ProviderError::InvalidRequest(_) => "invalid_request",
+            ProviderError::ModelNotFound { .. } => "model_not_found",
+            ProviderError::ContextLengthExceeded { .. } => "context_length_exceeded",
+            ProviderError::NetworkError(_) => "network_error",
+            ProviderError::ParseError(_) => "parse_error",
+            ProviderError::ApiError { .. } => "api_error",
+            ProviderError::Timeout(_) => "timeout",
+            ProviderError::InternalError(_) => "internal",
+        }
+    }
+
     /// Generate a unique request ID
     fn generate_request_id() -> String {
         use std::sync::atomic::{AtomicU64, Ordering};
@@ -174,6 +206,50 @@ impl MonitoredProvider {
     }
 }
 
+/// Wraps a provider's [`ResponseStream`] to record time-to-first-token and
+/// total stream duration as separate histograms, since neither is visible
+/// from the single `latency` measurement `complete` uses.
+struct InstrumentedStream {
+    inner: ResponseStream,
+    start: Instant,
+    first_token_recorded: bool,
+    completed: bool,
+    monitoring: Arc<MonitoringSystem>,
+    provider: String,
+    trace_id: String,
+}
+
+impl Stream for InstrumentedStream {
+    type Item = Result<String, ProviderError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let poll = this.inner.as_mut().poll_next(cx);
+
+        match &poll {
+            Poll::Ready(Some(Ok(_))) if !this.first_token_recorded => {
+                this.first_token_recorded = true;
+                let ttft = this.start.elapsed().as_secs_f64();
+                this.monitoring
+                    .record_time_to_first_token(&this.provider, ttft, Some(&this.trace_id));
+            }
+            // An error before any token means there is no first token to time.
+            Poll::Ready(Some(Err(_))) if !this.first_token_recorded => {
+                this.first_token_recorded = true;
+            }
+            Poll::Ready(None) if !this.completed => {
+                this.completed = true;
+                let duration = this.start.elapsed().as_secs_f64();
+                this.monitoring
+                    .record_stream_duration(&this.provider, duration, Some(&this.trace_id));
+            }
+            _ => {}
+        }
+
+        poll
+    }
+}
+
 /// Helper to wrap multiple providers with monitoring
 pub fn monitor_providers(
     providers: Vec<Arc<dyn Provider>>,
@@ -188,6 +264,8 @@ pub fn monitor_providers(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::monitoring::MonitoringConfig;
+    use futures::StreamExt;
 
     // Note: Actual testing would require mock providers
     #[test]
@@ -196,4 +274,74 @@ mod tests {
         assert!(cost > 0.0);
         assert!(cost < 1.0); // Sanity check
     }
+
+    #[test]
+    fn test_classify_error() {
+        assert_eq!(
+            MonitoredProvider::classify_error(&ProviderError::RateLimitExceeded { retry_after: None }),
+            "rate_limit"
+        );
+        assert_eq!(MonitoredProvider::classify_error(&ProviderError::InvalidApiKey), "invalid_key");
+        assert_eq!(
+            MonitoredProvider::classify_error(&ProviderError::InternalError("boom".to_string())),
+            "internal"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_instrumented_stream_records_ttft_and_duration() {
+        let config = MonitoringConfig::new().with_prometheus(false).with_websocket(false);
+        let monitoring = Arc::new(MonitoringSystem::new(config).await.unwrap());
+
+        let inner: ResponseStream = Box::pin(futures::stream::iter(vec![
+            Ok("hello".to_string()),
+            Ok(" world".to_string()),
+        ]));
+
+        let stream = InstrumentedStream {
+            inner,
+            start: Instant::now(),
+            first_token_recorded: false,
+            completed: false,
+            monitoring: monitoring.clone(),
+            provider: "openai".to_string(),
+            trace_id: "req_test".to_string(),
+        };
+
+        let chunks: Vec<_> = Box::pin(stream).collect().await;
+        assert_eq!(chunks.len(), 2);
+
+        let metrics = monitoring.get_metrics().await;
+        assert!(metrics.iter().any(|m| m.name == "llm_time_to_first_token_seconds"));
+        assert!(metrics.iter().any(|m| m.name == "llm_stream_duration_seconds"));
+    }
+
+    #[tokio::test]
+    async fn test_instrumented_stream_does_not_record_ttft_for_leading_error() {
+        let config = MonitoringConfig::new().with_prometheus(false).with_websocket(false);
+        let monitoring = Arc::new(MonitoringSystem::new(config).await.unwrap());
+
+        let inner: ResponseStream = Box::pin(futures::stream::iter(vec![
+            Err(ProviderError::RateLimitExceeded { retry_after: None }),
+            Ok("hello".to_string()),
+        ]));
+
+        let stream = InstrumentedStream {
+            inner,
+            start: Instant::now(),
+            first_token_recorded: false,
+            completed: false,
+            monitoring: monitoring.clone(),
+            provider: "openai".to_string(),
+            trace_id: "req_test".to_string(),
+        };
+
+        let chunks: Vec<_> = Box::pin(stream).collect().await;
+        assert_eq!(chunks.len(), 2);
+
+        // The leading error must not be counted as a successful "time to
+        // first token" sample.
+        let metrics = monitoring.get_metrics().await;
+        assert!(!metrics.iter().any(|m| m.name == "llm_time_to_first_token_seconds"));
+    }
 }
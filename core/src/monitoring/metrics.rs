@@ -115,6 +115,23 @@ pub struct HistogramBucket {
     pub le: f64,
     /// Cumulative count
     pub count: u64,
+    /// OpenMetrics exemplar for an observation that landed in this bucket,
+    /// so a p99 latency/cost spike can be traced back to the request that
+    /// produced it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exemplar: Option<Exemplar>,
+}
+
+/// An OpenMetrics exemplar: a single observation attached to a histogram
+/// bucket, identified by trace/request ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Exemplar {
+    /// Request or trace ID the observation belongs to
+    pub trace_id: String,
+    /// The observed value
+    pub value: f64,
+    /// When the observation was recorded
+    pub timestamp: DateTime<Utc>,
 }
 
 /// Summary value with quantiles
@@ -207,6 +224,42 @@ impl RequestMetric {
     }
 }
 
+/// Build a single-observation `HistogramValue` against `bucket_bounds`,
+/// attaching `trace_id` (if any) as an exemplar on the smallest bucket the
+/// observation falls into, per the OpenMetrics exemplar convention used by
+/// `prometheus-client`'s `observe_with_exemplar`.
+fn observe_histogram(
+    value: f64,
+    bucket_bounds: &[f64],
+    trace_id: Option<&str>,
+    timestamp: DateTime<Utc>,
+) -> HistogramValue {
+    let mut exemplar_placed = false;
+    let buckets = bucket_bounds
+        .iter()
+        .map(|&le| {
+            let count = if value <= le { 1 } else { 0 };
+            let exemplar = if count == 1 && !exemplar_placed {
+                exemplar_placed = true;
+                trace_id.map(|id| Exemplar {
+                    trace_id: id.to_string(),
+                    value,
+                    timestamp,
+                })
+            } else {
+                None
+            };
+            HistogramBucket { le, count, exemplar }
+        })
+        .collect();
+
+    HistogramValue {
+        buckets,
+        count: 1,
+        sum: value,
+    }
+}
+
 /// Latency metric for tracking response times
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LatencyMetric {
@@ -218,6 +271,10 @@ pub struct LatencyMetric {
     pub latency: f64,
     /// Timestamp
     pub timestamp: DateTime<Utc>,
+    /// Request/trace ID, attached as an exemplar on the matching bucket
+    pub trace_id: Option<String>,
+    /// Histogram bucket boundaries in seconds
+    pub buckets: Vec<f64>,
 }
 
 impl LatencyMetric {
@@ -227,34 +284,163 @@ impl LatencyMetric {
             model: model.into(),
             latency,
             timestamp: Utc::now(),
+            trace_id: None,
+            buckets: Self::default_buckets(),
         }
     }
 
+    /// Attach a request/trace ID so an outlier observation can be traced
+    /// back to the request that produced it
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Override the default histogram bucket boundaries
+    pub fn with_buckets(mut self, buckets: Vec<f64>) -> Self {
+        self.buckets = buckets;
+        self
+    }
+
     pub fn to_metric(&self) -> Metric {
         Metric::new(
             "llm_request_duration_seconds",
             MetricType::Histogram,
-            MetricValue::Histogram(HistogramValue {
-                buckets: Self::default_buckets(),
-                count: 1,
-                sum: self.latency,
-            }),
+            MetricValue::Histogram(observe_histogram(
+                self.latency,
+                &self.buckets,
+                self.trace_id.as_deref(),
+                self.timestamp,
+            )),
         )
         .with_label("provider", &self.provider)
         .with_label("model", &self.model)
         .with_help("Request duration in seconds")
     }
 
-    fn default_buckets() -> Vec<HistogramBucket> {
-        vec![
-            HistogramBucket { le: 0.1, count: 0 },
-            HistogramBucket { le: 0.5, count: 0 },
-            HistogramBucket { le: 1.0, count: 0 },
-            HistogramBucket { le: 2.0, count: 0 },
-            HistogramBucket { le: 5.0, count: 0 },
-            HistogramBucket { le: 10.0, count: 0 },
-            HistogramBucket { le: f64::INFINITY, count: 0 },
-        ]
+    /// Bucket boundaries tuned for seconds-scale LLM calls, rather than the
+    /// sub-second buckets typical of web request latency
+    pub fn default_buckets() -> Vec<f64> {
+        vec![0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, f64::INFINITY]
+    }
+}
+
+/// Time from a streaming request being sent to the first token arriving
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeToFirstTokenMetric {
+    /// Provider name
+    pub provider: String,
+    /// Model name
+    pub model: String,
+    /// Time to first token in seconds
+    pub ttft: f64,
+    /// Timestamp
+    pub timestamp: DateTime<Utc>,
+    /// Request/trace ID, attached as an exemplar on the matching bucket
+    pub trace_id: Option<String>,
+    /// Histogram bucket boundaries in seconds
+    pub buckets: Vec<f64>,
+}
+
+impl TimeToFirstTokenMetric {
+    pub fn new(provider: impl Into<String>, model: impl Into<String>, ttft: f64) -> Self {
+        Self {
+            provider: provider.into(),
+            model: model.into(),
+            ttft,
+            timestamp: Utc::now(),
+            trace_id: None,
+            buckets: Self::default_buckets(),
+        }
+    }
+
+    /// Attach a request/trace ID so an outlier observation can be traced
+    /// back to the request that produced it
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    pub fn to_metric(&self) -> Metric {
+        Metric::new(
+            "llm_time_to_first_token_seconds",
+            MetricType::Histogram,
+            MetricValue::Histogram(observe_histogram(
+                self.ttft,
+                &self.buckets,
+                self.trace_id.as_deref(),
+                self.timestamp,
+            )),
+        )
+        .with_label("provider", &self.provider)
+        .with_label("model", &self.model)
+        .with_help("Time to first streamed token in seconds")
+    }
+
+    /// Bucket boundaries biased toward the sub-second range where TTFT
+    /// usually lives, unlike full-response latency
+    pub fn default_buckets() -> Vec<f64> {
+        vec![0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, f64::INFINITY]
+    }
+}
+
+/// Total wall-clock duration of a streaming response, from request start
+/// to the stream closing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamDurationMetric {
+    /// Provider name
+    pub provider: String,
+    /// Model name
+    pub model: String,
+    /// Total stream duration in seconds
+    pub duration: f64,
+    /// Timestamp
+    pub timestamp: DateTime<Utc>,
+    /// Request/trace ID, attached as an exemplar on the matching bucket
+    pub trace_id: Option<String>,
+    /// Histogram bucket boundaries in seconds
+    pub buckets: Vec<f64>,
+}
+
+impl StreamDurationMetric {
+    pub fn new(provider: impl Into<String>, model: impl Into<String>, duration: f64) -> Self {
+        Self {
+            provider: provider.into(),
+            model: model.into(),
+            duration,
+            timestamp: Utc::now(),
+            trace_id: None,
+            buckets: Self::default_buckets(),
+        }
+    }
+
+    /// Attach a request/trace ID so an outlier observation can be traced
+    /// back to the request that produced it
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    pub fn to_metric(&self) -> Metric {
+        Metric::new(
+            "llm_stream_duration_seconds",
+            MetricType::Histogram,
+            MetricValue::Histogram(observe_histogram(
+                self.duration,
+                &self.buckets,
+                self.trace_id.as_deref(),
+                self.timestamp,
+            )),
+        )
+        .with_label("provider", &self.provider)
+        .with_label("model", &self.model)
+        .with_help("Total streaming response duration in seconds")
+    }
+
+    /// Same shape as [`LatencyMetric::default_buckets`] since a stream's
+    /// total duration is on the same timescale as a non-streamed request
+    pub fn default_buckets() -> Vec<f64> {
+        LatencyMetric::default_buckets()
     }
 }
 
@@ -322,6 +508,10 @@ pub struct CostMetric {
     pub cost: f64,
     /// Timestamp
     pub timestamp: DateTime<Utc>,
+    /// Request/trace ID, attached as an exemplar on the matching bucket
+    pub trace_id: Option<String>,
+    /// Histogram bucket boundaries in USD
+    pub buckets: Vec<f64>,
 }
 
 impl CostMetric {
@@ -331,10 +521,44 @@ impl CostMetric {
             model: model.into(),
             cost,
             timestamp: Utc::now(),
+            trace_id: None,
+            buckets: Self::default_buckets(),
         }
     }
 
+    /// Attach a request/trace ID so an outlier observation can be traced
+    /// back to the request that produced it
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Override the default histogram bucket boundaries
+    pub fn with_buckets(mut self, buckets: Vec<f64>) -> Self {
+        self.buckets = buckets;
+        self
+    }
+
     pub fn to_metric(&self) -> Metric {
+        Metric::new(
+            "llm_cost_usd",
+            MetricType::Histogram,
+            MetricValue::Histogram(observe_histogram(
+                self.cost,
+                &self.buckets,
+                self.trace_id.as_deref(),
+                self.timestamp,
+            )),
+        )
+        .with_label("provider", &self.provider)
+        .with_label("model", &self.model)
+        .with_help("Request cost in USD")
+    }
+
+    /// Cumulative cost counter, published alongside [`Self::to_metric`]'s
+    /// per-request histogram so `rate()`/`increase()` queries against total
+    /// spend keep working even where a histogram's `_sum` isn't convenient
+    pub fn to_counter_metric(&self) -> Metric {
         Metric::new(
             "llm_cost_usd_total",
             MetricType::Counter,
@@ -344,6 +568,12 @@ impl CostMetric {
         .with_label("model", &self.model)
         .with_help("Total cost in USD (micro-dollars)")
     }
+
+    /// Bucket boundaries tuned for per-request LLM costs, which are
+    /// typically fractions of a dollar
+    pub fn default_buckets() -> Vec<f64> {
+        vec![0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, f64::INFINITY]
+    }
 }
 
 /// Error metric for tracking failures
@@ -465,6 +695,22 @@ mod tests {
         assert_eq!(metric.name, "llm_request_duration_seconds");
     }
 
+    #[test]
+    fn test_time_to_first_token_metric() {
+        let ttft = TimeToFirstTokenMetric::new("openai", "gpt-4", 0.2);
+        let metric = ttft.to_metric();
+
+        assert_eq!(metric.name, "llm_time_to_first_token_seconds");
+    }
+
+    #[test]
+    fn test_stream_duration_metric() {
+        let duration = StreamDurationMetric::new("openai", "gpt-4", 3.5);
+        let metric = duration.to_metric();
+
+        assert_eq!(metric.name, "llm_stream_duration_seconds");
+    }
+
     #[test]
     fn test_token_metric() {
         let tokens = TokenMetric::new("openai", "gpt-4", 100, 50);
@@ -480,7 +726,49 @@ mod tests {
         let cost = CostMetric::new("openai", "gpt-4", 0.05);
         let metric = cost.to_metric();
 
+        assert_eq!(metric.name, "llm_cost_usd");
+    }
+
+    #[test]
+    fn test_cost_metric_counter() {
+        let cost = CostMetric::new("openai", "gpt-4", 0.05);
+        let metric = cost.to_counter_metric();
+
         assert_eq!(metric.name, "llm_cost_usd_total");
+        assert!(matches!(metric.value, MetricValue::Counter(50_000)));
+    }
+
+    #[test]
+    fn test_latency_metric_exemplar() {
+        let latency = LatencyMetric::new("openai", "gpt-4", 1.5).with_trace_id("req_42");
+        let metric = latency.to_metric();
+
+        let MetricValue::Histogram(histogram) = &metric.value else {
+            panic!("expected a histogram value");
+        };
+
+        let exemplar_bucket = histogram
+            .buckets
+            .iter()
+            .find(|b| b.exemplar.is_some())
+            .expect("one bucket should carry an exemplar");
+        let exemplar = exemplar_bucket.exemplar.as_ref().unwrap();
+
+        assert_eq!(exemplar.trace_id, "req_42");
+        assert_eq!(exemplar.value, 1.5);
+        assert_eq!(exemplar_bucket.le, 2.5);
+    }
+
+    #[test]
+    fn test_cost_metric_without_trace_id_has_no_exemplar() {
+        let cost = CostMetric::new("openai", "gpt-4", 0.05);
+        let metric = cost.to_metric();
+
+        let MetricValue::Histogram(histogram) = &metric.value else {
+            panic!("expected a histogram value");
+        };
+
+        assert!(histogram.buckets.iter().all(|b| b.exemplar.is_none()));
     }
 
     #[test]
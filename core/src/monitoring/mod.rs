@@ -76,7 +76,7 @@
 //!
 //!     // Record metrics
 //!     monitoring.record_request("openai", "gpt-4");
-//!     monitoring.record_latency("openai", 1.5);
+//!     monitoring.record_latency("openai", 1.5, Some("req_123"));
 //!     monitoring.record_tokens("openai", 150, 50);
 //!
 //!     Ok(())
@@ -91,6 +91,12 @@ pub mod dashboard;
 pub mod collector;
 pub mod integration;
 
+/// Ships metrics to an OpenTelemetry collector over OTLP/gRPC; optional
+/// because the `opentelemetry-otlp` dependency is only needed by
+/// deployments that don't scrape Prometheus directly.
+#[cfg(feature = "otlp")]
+pub mod otlp;
+
 pub use metrics::{
     Metric, MetricType, MetricValue, MetricLabels,
     RequestMetric, LatencyMetric, TokenMetric, CostMetric, ErrorMetric,
@@ -107,6 +113,9 @@ pub use dashboard::{Dashboard, DashboardConfig};
 pub use collector::{MetricCollector, CollectorConfig};
 pub use integration::{MonitoredProvider, monitor_providers};
 
+#[cfg(feature = "otlp")]
+pub use otlp::{OtlpExporter, OtlpConfig};
+
 use anyhow::Result;
 use std::sync::Arc;
 use parking_lot::RwLock;
@@ -118,6 +127,8 @@ pub struct MonitoringSystem {
     prometheus: Arc<PrometheusExporter>,
     websocket: Arc<WebSocketServer>,
     collector: Arc<MetricCollector>,
+    #[cfg(feature = "otlp")]
+    otlp: Arc<OtlpExporter>,
 }
 
 /// Configuration for the monitoring system
@@ -139,6 +150,10 @@ pub struct MonitoringConfig {
     pub retention_period: u64,
     /// Enable detailed metrics (may impact performance)
     pub detailed_metrics: bool,
+    /// Enable OTLP metrics export
+    pub otlp_enabled: bool,
+    /// OTLP/gRPC collector endpoint
+    pub otlp_endpoint: String,
 }
 
 impl Default for MonitoringConfig {
@@ -152,6 +167,8 @@ impl Default for MonitoringConfig {
             dashboard_port: 3000,
             retention_period: 3600, // 1 hour
             detailed_metrics: false,
+            otlp_enabled: false,
+            otlp_endpoint: "http://localhost:4317".to_string(),
         }
     }
 }
@@ -209,6 +226,18 @@ impl MonitoringConfig {
         self.detailed_metrics = enabled;
         self
     }
+
+    /// Enable OTLP metrics export
+    pub fn with_otlp(mut self, enabled: bool) -> Self {
+        self.otlp_enabled = enabled;
+        self
+    }
+
+    /// Set the OTLP collector endpoint
+    pub fn with_otlp_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.otlp_endpoint = endpoint.into();
+        self
+    }
 }
 
 impl MonitoringSystem {
@@ -219,8 +248,9 @@ impl MonitoringSystem {
         let prometheus_config = PrometheusConfig {
             port: config.prometheus_port,
             enabled: config.prometheus_enabled,
+            ..PrometheusConfig::default()
         };
-        let prometheus = Arc::new(PrometheusExporter::new(prometheus_config)?);
+        let prometheus = Arc::new(PrometheusExporter::new(prometheus_config, event_bus.clone())?);
 
         let websocket_config = WebSocketConfig {
             port: config.websocket_port,
@@ -234,12 +264,24 @@ impl MonitoringSystem {
         };
         let collector = Arc::new(MetricCollector::new(collector_config, event_bus.clone()));
 
+        #[cfg(feature = "otlp")]
+        let otlp = {
+            let otlp_config = OtlpConfig {
+                enabled: config.otlp_enabled,
+                endpoint: config.otlp_endpoint.clone(),
+                ..OtlpConfig::default()
+            };
+            Arc::new(OtlpExporter::new(otlp_config)?)
+        };
+
         Ok(Self {
             config,
             event_bus,
             prometheus,
             websocket,
             collector,
+            #[cfg(feature = "otlp")]
+            otlp,
         })
     }
 
@@ -260,6 +302,12 @@ impl MonitoringSystem {
         self.collector.start().await?;
         tracing::info!("Metric collector started");
 
+        #[cfg(feature = "otlp")]
+        if self.config.otlp_enabled {
+            self.otlp.start(self.event_bus.clone()).await?;
+            tracing::info!("OTLP exporter started, shipping to {}", self.config.otlp_endpoint);
+        }
+
         Ok(())
     }
 
@@ -277,6 +325,11 @@ impl MonitoringSystem {
 
         self.collector.stop().await?;
 
+        #[cfg(feature = "otlp")]
+        if self.config.otlp_enabled {
+            self.otlp.stop().await?;
+        }
+
         Ok(())
     }
 
@@ -286,9 +339,24 @@ impl MonitoringSystem {
         self.event_bus.publish(event);
     }
 
-    /// Record a latency metric
-    pub fn record_latency(&self, provider: &str, latency: f64) {
-        let event = MonitoringEvent::latency(provider, latency);
+    /// Record a latency metric, optionally tagged with a request/trace ID
+    /// so a p99 bucket can be traced back to the request that produced it
+    pub fn record_latency(&self, provider: &str, latency: f64, trace_id: Option<&str>) {
+        let event = MonitoringEvent::latency(provider, latency, trace_id);
+        self.event_bus.publish(event);
+    }
+
+    /// Record the time to first token for a streaming request, optionally
+    /// tagged with a request/trace ID
+    pub fn record_time_to_first_token(&self, provider: &str, ttft: f64, trace_id: Option<&str>) {
+        let event = MonitoringEvent::time_to_first_token(provider, ttft, trace_id);
+        self.event_bus.publish(event);
+    }
+
+    /// Record the total duration of a completed streaming request,
+    /// optionally tagged with a request/trace ID
+    pub fn record_stream_duration(&self, provider: &str, duration: f64, trace_id: Option<&str>) {
+        let event = MonitoringEvent::stream_duration(provider, duration, trace_id);
         self.event_bus.publish(event);
     }
 
@@ -298,10 +366,11 @@ impl MonitoringSystem {
         self.event_bus.publish(event);
     }
 
-    /// Record cost
-    pub fn record_cost(&self, provider: &str, cost: f64) {
-        let event = MonitoringEvent::cost(provider, cost);
-        self.event_bus.publish(event);
+    /// Record cost, optionally tagged with a request/trace ID so a cost
+    /// outlier bucket can be traced back to the request that produced it
+    pub fn record_cost(&self, provider: &str, cost: f64, trace_id: Option<&str>) {
+        self.event_bus.publish(MonitoringEvent::cost_total(provider, cost));
+        self.event_bus.publish(MonitoringEvent::cost(provider, cost, trace_id));
     }
 
     /// Record an error
@@ -342,6 +411,16 @@ mod tests {
         assert!(config.detailed_metrics);
     }
 
+    #[test]
+    fn test_monitoring_config_otlp() {
+        let config = MonitoringConfig::new()
+            .with_otlp(true)
+            .with_otlp_endpoint("http://collector:4317");
+
+        assert!(config.otlp_enabled);
+        assert_eq!(config.otlp_endpoint, "http://collector:4317");
+    }
+
     #[tokio::test]
     async fn test_monitoring_system_creation() {
         let config = MonitoringConfig::new()
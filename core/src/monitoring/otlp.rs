@@ -0,0 +1,269 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! OTLP metrics export, as an alternative to (or alongside) the Prometheus
+//! scrape endpoint.
+//!
+//! Many deployments already run an OpenTelemetry collector and want metrics
+//! fanned out to Datadog/Tempo/Honeycomb rather than scraped directly, so
+//! `OtlpExporter` subscribes to the same `EventBus` as `PrometheusExporter`,
+//! translates each `Metric` into an OTLP counter or histogram instrument,
+//! and flushes to the collector on an interval.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use opentelemetry::metrics::{Counter, Histogram, Meter};
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use parking_lot::RwLock;
+use tokio::task::JoinHandle;
+
+use crate::monitoring::events::{EventBus, EventSubscriber, EventPayload, MonitoringEvent};
+use crate::monitoring::metrics::{Metric, MetricValue};
+
+/// Configuration for the OTLP metrics exporter.
+#[derive(Debug, Clone)]
+pub struct OtlpConfig {
+    /// Enable OTLP export
+    pub enabled: bool,
+    /// OTLP/gRPC collector endpoint, e.g. `http://localhost:4317`
+    pub endpoint: String,
+    /// How often buffered instruments are flushed to the collector
+    pub export_interval_secs: u64,
+    /// Span-export flag, stored on the config only: `OtlpExporter` does not
+    /// read it and exports metrics only, never spans
+    pub export_spans: bool,
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: "http://localhost:4317".to_string(),
+            export_interval_secs: 10,
+            export_spans: false,
+        }
+    }
+}
+
+impl OtlpConfig {
+    /// Create a new OTLP exporter configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the OTLP collector endpoint
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// Set the export flush interval
+    pub fn with_export_interval_secs(mut self, seconds: u64) -> Self {
+        self.export_interval_secs = seconds;
+        self
+    }
+
+    /// Set the `export_spans` flag; `OtlpExporter` exports no spans
+    /// whatever its value
+    pub fn with_export_spans(mut self, enabled: bool) -> Self {
+        self.export_spans = enabled;
+        self
+    }
+}
+
+/// OTLP metrics exporter that mirrors the Prometheus exporter but ships
+/// metrics to a collector instead of serving a scrape endpoint.
+pub struct OtlpExporter {
+    config: OtlpConfig,
+    meter: Option<Meter>,
+    counters: Arc<RwLock<HashMap<String, Counter<u64>>>>,
+    histograms: Arc<RwLock<HashMap<String, Histogram<f64>>>>,
+    flush_handle: Arc<RwLock<Option<JoinHandle<()>>>>,
+}
+
+impl OtlpExporter {
+    /// Create a new OTLP exporter, standing up the OTel meter provider if enabled
+    pub fn new(config: OtlpConfig) -> Result<Self> {
+        let meter = if config.enabled {
+            Some(Self::init_meter(&config)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            config,
+            meter,
+            counters: Arc::new(RwLock::new(HashMap::new())),
+            histograms: Arc::new(RwLock::new(HashMap::new())),
+            flush_handle: Arc::new(RwLock::new(None)),
+        })
+    }
+
+    fn init_meter(config: &OtlpConfig) -> Result<Meter> {
+        let provider = opentelemetry_otlp::new_pipeline()
+            .metrics(opentelemetry_sdk::runtime::Tokio)
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(&config.endpoint),
+            )
+            .with_period(Duration::from_secs(config.export_interval_secs))
+            .build()
+            .context("failed to build OTLP metrics pipeline")?;
+
+        global::set_meter_provider(provider);
+        Ok(global::meter("llm_test_bench"))
+    }
+
+    /// Subscribe to the event bus and start periodic flushing
+    pub async fn start(&self, event_bus: Arc<EventBus>) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let subscriber = Arc::new(OtlpSubscriber {
+            meter: self.meter.clone(),
+            counters: self.counters.clone(),
+            histograms: self.histograms.clone(),
+        });
+        event_bus.add_subscriber(subscriber);
+
+        let interval_secs = self.config.export_interval_secs.max(1);
+        let flush = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                global::meter_provider()
+                    .force_flush()
+                    .unwrap_or_else(|e| tracing::warn!("OTLP flush failed: {}", e));
+            }
+        });
+
+        let mut handle = self.flush_handle.write();
+        *handle = Some(flush);
+
+        Ok(())
+    }
+
+    /// Stop periodic flushing and perform one final flush
+    pub async fn stop(&self) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(h) = self.flush_handle.write().take() {
+            h.abort();
+        }
+
+        global::meter_provider()
+            .force_flush()
+            .context("final OTLP flush failed")?;
+        global::shutdown_meter_provider();
+
+        Ok(())
+    }
+}
+
+/// Event subscriber that mirrors published metrics into OTel instruments
+struct OtlpSubscriber {
+    meter: Option<Meter>,
+    counters: Arc<RwLock<HashMap<String, Counter<u64>>>>,
+    histograms: Arc<RwLock<HashMap<String, Histogram<f64>>>>,
+}
+
+impl EventSubscriber for OtlpSubscriber {
+    fn on_event(&self, event: &MonitoringEvent) {
+        if let EventPayload::Metric(metric_event) = &event.payload {
+            self.record_metric(&metric_event.metric);
+        }
+    }
+}
+
+impl OtlpSubscriber {
+    fn record_metric(&self, metric: &Metric) {
+        let Some(meter) = &self.meter else {
+            return;
+        };
+
+        let attributes: Vec<KeyValue> = metric
+            .labels
+            .all()
+            .iter()
+            .map(|(k, v)| KeyValue::new(k.clone(), v.clone()))
+            .collect();
+
+        match &metric.value {
+            MetricValue::Counter(value) => {
+                self.counter_for(meter, &metric.name).add(*value, &attributes);
+            }
+            MetricValue::Gauge(value) => {
+                // OTel has no synchronous gauge-set instrument wired up here,
+                // so gauges are recorded as a single-sample histogram.
+                self.histogram_for(meter, &metric.name).record(*value, &attributes);
+            }
+            MetricValue::Histogram(histogram) => {
+                self.histogram_for(meter, &metric.name).record(histogram.sum, &attributes);
+            }
+            MetricValue::Summary(summary) => {
+                self.histogram_for(meter, &metric.name).record(summary.sum, &attributes);
+            }
+        }
+    }
+
+    fn counter_for(&self, meter: &Meter, name: &str) -> Counter<u64> {
+        let mut counters = self.counters.write();
+        counters
+            .entry(name.to_string())
+            .or_insert_with(|| meter.u64_counter(name.to_string()).build())
+            .clone()
+    }
+
+    fn histogram_for(&self, meter: &Meter, name: &str) -> Histogram<f64> {
+        let mut histograms = self.histograms.write();
+        histograms
+            .entry(name.to_string())
+            .or_insert_with(|| meter.f64_histogram(name.to_string()).build())
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_otlp_config_defaults() {
+        let config = OtlpConfig::default();
+        assert!(!config.enabled);
+        assert_eq!(config.endpoint, "http://localhost:4317");
+        assert_eq!(config.export_interval_secs, 10);
+    }
+
+    #[test]
+    fn test_otlp_config_builder() {
+        let config = OtlpConfig::new()
+            .with_endpoint("http://collector:4317")
+            .with_export_interval_secs(30)
+            .with_export_spans(true);
+
+        assert_eq!(config.endpoint, "http://collector:4317");
+        assert_eq!(config.export_interval_secs, 30);
+        assert!(config.export_spans);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_exporter_is_a_no_op() {
+        let exporter = OtlpExporter::new(OtlpConfig::default()).unwrap();
+        let event_bus = Arc::new(EventBus::new());
+
+        assert!(exporter.start(event_bus).await.is_ok());
+        assert!(exporter.stop().await.is_ok());
+    }
+}
@@ -0,0 +1,527 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Prometheus metrics export: a `/metrics` scrape endpoint, and an
+//! optional Pushgateway mode for benchmark runs that may exit before a
+//! scrape interval elapses.
+
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{extract::State, routing::get, Router};
+use parking_lot::RwLock;
+use tokio::task::JoinHandle;
+
+use crate::monitoring::events::{EventBus, EventPayload, EventSubscriber, MonitoringEvent};
+use crate::monitoring::metrics::{HistogramValue, Metric, MetricValue, SummaryValue};
+
+/// Prometheus exporter configuration
+#[derive(Debug, Clone)]
+pub struct PrometheusConfig {
+    /// Port to serve the `/metrics` scrape endpoint on
+    pub port: u16,
+    /// Enable the exporter
+    pub enabled: bool,
+    /// Optional Pushgateway target for short-lived runs
+    pub pushgateway: Option<PushgatewayConfig>,
+}
+
+impl Default for PrometheusConfig {
+    fn default() -> Self {
+        Self {
+            port: 9090,
+            enabled: true,
+            pushgateway: None,
+        }
+    }
+}
+
+impl PrometheusConfig {
+    /// Create a new Prometheus exporter configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the scrape endpoint port
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Push metrics to `url` under `job`, grouped by `grouping_labels`, in
+    /// addition to serving the scrape endpoint. `start` always serves
+    /// `/metrics` as well; there is no push-only mode.
+    pub fn with_pushgateway(
+        mut self,
+        url: impl Into<String>,
+        job: impl Into<String>,
+        grouping_labels: HashMap<String, String>,
+    ) -> Self {
+        self.pushgateway = Some(PushgatewayConfig::new(url, job, grouping_labels));
+        self
+    }
+}
+
+/// Configuration for pushing metrics to a Prometheus Pushgateway
+#[derive(Debug, Clone)]
+pub struct PushgatewayConfig {
+    /// Base Pushgateway URL, e.g. `http://localhost:9091`
+    pub url: String,
+    /// Job name used in the push URL
+    pub job: String,
+    /// Grouping key labels appended to the push URL
+    pub grouping_labels: HashMap<String, String>,
+    /// How often the registry is pushed while the benchmark is running
+    pub push_interval_secs: u64,
+    /// Maximum retry attempts for a failed push
+    pub max_retries: u32,
+}
+
+impl PushgatewayConfig {
+    /// Create a new Pushgateway configuration with default cadence/retries
+    pub fn new(
+        url: impl Into<String>,
+        job: impl Into<String>,
+        grouping_labels: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            url: url.into(),
+            job: job.into(),
+            grouping_labels,
+            push_interval_secs: 15,
+            max_retries: 3,
+        }
+    }
+
+    /// Set how often the registry is pushed while running
+    pub fn with_push_interval_secs(mut self, seconds: u64) -> Self {
+        self.push_interval_secs = seconds;
+        self
+    }
+
+    /// The full `/metrics/job/<job>/<label>/<value>/...` push URL
+    fn push_url(&self) -> String {
+        let mut url = format!("{}/metrics/job/{}", self.url.trim_end_matches('/'), self.job);
+
+        let mut labels: Vec<_> = self.grouping_labels.iter().collect();
+        labels.sort_by_key(|(k, _)| k.as_str());
+        for (key, value) in labels {
+            url.push_str(&format!("/{}/{}", key, value));
+        }
+
+        url
+    }
+}
+
+/// Prometheus metrics exporter, serving a scrape endpoint and, when one is
+/// configured, also pushing to a Pushgateway
+pub struct PrometheusExporter {
+    config: PrometheusConfig,
+    registry: Arc<RwLock<HashMap<String, Metric>>>,
+    server_handle: Arc<RwLock<Option<JoinHandle<()>>>>,
+    push_handle: Arc<RwLock<Option<JoinHandle<()>>>>,
+    http_client: reqwest::Client,
+}
+
+impl PrometheusExporter {
+    /// Create a new Prometheus exporter, subscribing to `event_bus`
+    pub fn new(config: PrometheusConfig, event_bus: Arc<EventBus>) -> Result<Self> {
+        let registry = Arc::new(RwLock::new(HashMap::new()));
+
+        let subscriber = Arc::new(PrometheusSubscriber {
+            registry: registry.clone(),
+        });
+        event_bus.add_subscriber(subscriber);
+
+        Ok(Self {
+            config,
+            registry,
+            server_handle: Arc::new(RwLock::new(None)),
+            push_handle: Arc::new(RwLock::new(None)),
+            http_client: reqwest::Client::new(),
+        })
+    }
+
+    /// Start the scrape server, plus the Pushgateway push loop when one is
+    /// configured
+    pub async fn start(&self) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let registry = self.registry.clone();
+        let app = Router::new()
+            .route("/metrics", get(scrape_handler))
+            .with_state(registry);
+
+        let port = self.config.port;
+        let listener = tokio::net::TcpListener::bind(("0.0.0.0", port))
+            .await
+            .with_context(|| format!("failed to bind Prometheus scrape endpoint on port {}", port))?;
+
+        let server = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                tracing::error!("Prometheus scrape server exited: {}", e);
+            }
+        });
+        *self.server_handle.write() = Some(server);
+
+        if let Some(pushgateway) = self.config.pushgateway.clone() {
+            let registry = self.registry.clone();
+            let client = self.http_client.clone();
+            let push = tokio::spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(pushgateway.push_interval_secs));
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = push_once(&client, &pushgateway, &registry).await {
+                        tracing::warn!("Pushgateway push failed: {}", e);
+                    }
+                }
+            });
+            *self.push_handle.write() = Some(push);
+        }
+
+        Ok(())
+    }
+
+    /// Stop the scrape server and push loop, performing one last
+    /// synchronous push so final totals reach the gateway
+    pub async fn stop(&self) -> Result<()> {
+        if let Some(h) = self.server_handle.write().take() {
+            h.abort();
+        }
+        if let Some(h) = self.push_handle.write().take() {
+            h.abort();
+        }
+
+        if let Some(pushgateway) = &self.config.pushgateway {
+            push_once(&self.http_client, pushgateway, &self.registry)
+                .await
+                .context("final Pushgateway flush failed")?;
+        }
+
+        Ok(())
+    }
+
+    /// Render the current registry as Prometheus text exposition format
+    pub fn render(&self) -> String {
+        render_registry(&self.registry.read())
+    }
+}
+
+/// Push the current registry to the Pushgateway once, retrying any failed
+/// attempt (error status or request error) up to `max_retries` times with
+/// exponential backoff
+async fn push_once(
+    client: &reqwest::Client,
+    config: &PushgatewayConfig,
+    registry: &RwLock<HashMap<String, Metric>>,
+) -> Result<()> {
+    let body = render_registry(&registry.read());
+    let url = config.push_url();
+
+    let mut attempt = 0;
+    loop {
+        let result = client.post(&url).body(body.clone()).send().await;
+
+        match result {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(response) if attempt >= config.max_retries => {
+                bail!("Pushgateway returned {} after {} attempts", response.status(), attempt + 1);
+            }
+            Err(e) if attempt >= config.max_retries => {
+                return Err(e).context("Pushgateway request failed after retries");
+            }
+            _ => {
+                attempt += 1;
+                let backoff = Duration::from_millis(200 * 2u64.pow(attempt));
+                tokio::time::sleep(backoff).await;
+            }
+        }
+    }
+}
+
+async fn scrape_handler(State(registry): State<Arc<RwLock<HashMap<String, Metric>>>>) -> String {
+    render_registry(&registry.read())
+}
+
+/// Render a metric registry in Prometheus text exposition format
+fn render_registry(registry: &HashMap<String, Metric>) -> String {
+    let mut out = String::new();
+
+    for metric in registry.values() {
+        if let Some(help) = &metric.help {
+            out.push_str(&format!("# HELP {} {}\n", metric.name, help));
+        }
+        out.push_str(&format!("# TYPE {} {}\n", metric.name, prometheus_type(metric)));
+
+        let labels = render_labels(metric);
+        match &metric.value {
+            MetricValue::Counter(v) => out.push_str(&format!("{}{} {}\n", metric.name, labels, v)),
+            MetricValue::Gauge(v) => out.push_str(&format!("{}{} {}\n", metric.name, labels, v)),
+            MetricValue::Histogram(h) => render_histogram(&mut out, &metric.name, &labels, h),
+            MetricValue::Summary(s) => render_summary(&mut out, &metric.name, &labels, s),
+        }
+    }
+
+    out
+}
+
+fn prometheus_type(metric: &Metric) -> &'static str {
+    match &metric.value {
+        MetricValue::Counter(_) => "counter",
+        MetricValue::Gauge(_) => "gauge",
+        MetricValue::Histogram(_) => "histogram",
+        MetricValue::Summary(_) => "summary",
+    }
+}
+
+fn render_labels(metric: &Metric) -> String {
+    if metric.labels.is_empty() {
+        return String::new();
+    }
+
+    let mut pairs: Vec<_> = metric.labels.all().iter().collect();
+    pairs.sort_by_key(|(k, _)| k.as_str());
+
+    let rendered = pairs
+        .into_iter()
+        .map(|(k, v)| format!("{}=\"{}\"", k, v))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("{{{}}}", rendered)
+}
+
+fn render_histogram(out: &mut String, name: &str, labels: &str, histogram: &HistogramValue) {
+    for bucket in &histogram.buckets {
+        let le = if bucket.le.is_infinite() { "+Inf".to_string() } else { bucket.le.to_string() };
+        let bucket_labels = with_extra_label(labels, "le", &le);
+        out.push_str(&format!("{}_bucket{} {}", name, bucket_labels, bucket.count));
+        if let Some(exemplar) = &bucket.exemplar {
+            out.push_str(&format!(
+                " # {{trace_id=\"{}\"}} {} {}",
+                exemplar.trace_id,
+                exemplar.value,
+                exemplar.timestamp.timestamp_millis() as f64 / 1000.0
+            ));
+        }
+        out.push('\n');
+    }
+    out.push_str(&format!("{}_sum{} {}\n", name, labels, histogram.sum));
+    out.push_str(&format!("{}_count{} {}\n", name, labels, histogram.count));
+}
+
+fn render_summary(out: &mut String, name: &str, labels: &str, summary: &SummaryValue) {
+    for quantile in &summary.quantiles {
+        let quantile_labels = with_extra_label(labels, "quantile", &quantile.quantile.to_string());
+        out.push_str(&format!("{}{} {}\n", name, quantile_labels, quantile.value));
+    }
+    out.push_str(&format!("{}_sum{} {}\n", name, labels, summary.sum));
+    out.push_str(&format!("{}_count{} {}\n", name, labels, summary.count));
+}
+
+fn with_extra_label(labels: &str, key: &str, value: &str) -> String {
+    let extra = format!("{}=\"{}\"", key, value);
+    if labels.is_empty() {
+        format!("{{{}}}", extra)
+    } else {
+        // Insert before the closing brace of the existing label set
+        format!("{},{}}}", &labels[..labels.len() - 1], extra)
+    }
+}
+
+/// Event subscriber that mirrors published metrics into the registry
+struct PrometheusSubscriber {
+    registry: Arc<RwLock<HashMap<String, Metric>>>,
+}
+
+impl EventSubscriber for PrometheusSubscriber {
+    fn on_event(&self, event: &MonitoringEvent) {
+        if let EventPayload::Metric(metric_event) = &event.payload {
+            let metric = &metric_event.metric;
+            let key = format!("{}:{:?}", metric.name, metric.labels.all());
+
+            let mut registry = self.registry.write();
+            match registry.get_mut(&key) {
+                Some(existing) => accumulate_metric(existing, metric),
+                None => {
+                    registry.insert(key, metric.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Folds a newly-published metric event into the value already stored for
+/// its key, rather than overwriting it.
+///
+/// `RequestMetric`/`ErrorMetric::to_metric()` and friends emit a per-event
+/// delta (e.g. `MetricValue::Counter(1)`), not a running total, so counters
+/// and histogram/summary accumulators must sum here - otherwise a counter
+/// forever reads as whatever the single most recent event reported, and
+/// `rate()`/`increase()` queries against it return nothing useful. Gauges
+/// represent a point-in-time value rather than a delta, so the latest event
+/// simply replaces the stored one.
+fn accumulate_metric(existing: &mut Metric, incoming: &Metric) {
+    existing.timestamp = incoming.timestamp;
+
+    match (&mut existing.value, &incoming.value) {
+        (MetricValue::Counter(total), MetricValue::Counter(delta)) => {
+            *total += delta;
+        }
+        (MetricValue::Gauge(_), MetricValue::Gauge(value)) => {
+            existing.value = MetricValue::Gauge(*value);
+        }
+        (MetricValue::Histogram(total), MetricValue::Histogram(delta)) => {
+            merge_histogram(total, delta);
+        }
+        (MetricValue::Summary(total), MetricValue::Summary(delta)) => {
+            total.count += delta.count;
+            total.sum += delta.sum;
+            total.quantiles = delta.quantiles.clone();
+        }
+        _ => {
+            // Mismatched value kinds for the same key shouldn't happen in
+            // practice; fall back to the latest event rather than panicking.
+            existing.value = incoming.value.clone();
+        }
+    }
+}
+
+/// Merges `delta`'s per-bucket counts (and overall count/sum) into `total`,
+/// keeping the most recent exemplar for any bucket `delta` touched.
+fn merge_histogram(total: &mut HistogramValue, delta: &HistogramValue) {
+    total.count += delta.count;
+    total.sum += delta.sum;
+
+    for delta_bucket in &delta.buckets {
+        match total.buckets.iter_mut().find(|bucket| bucket.le == delta_bucket.le) {
+            Some(bucket) => {
+                bucket.count += delta_bucket.count;
+                if delta_bucket.exemplar.is_some() {
+                    bucket.exemplar = delta_bucket.exemplar.clone();
+                }
+            }
+            None => total.buckets.push(delta_bucket.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitoring::metrics::{HistogramBucket, Metric, MetricType, MetricValue};
+
+    #[test]
+    fn test_pushgateway_push_url() {
+        let mut labels = HashMap::new();
+        labels.insert("instance".to_string(), "bench-1".to_string());
+
+        let config = PushgatewayConfig::new("http://localhost:9091", "llm_bench", labels);
+        assert_eq!(config.push_url(), "http://localhost:9091/metrics/job/llm_bench/instance/bench-1");
+    }
+
+    #[test]
+    fn test_pushgateway_push_url_no_labels() {
+        let config = PushgatewayConfig::new("http://localhost:9091/", "llm_bench", HashMap::new());
+        assert_eq!(config.push_url(), "http://localhost:9091/metrics/job/llm_bench");
+    }
+
+    #[test]
+    fn test_config_with_pushgateway_builder() {
+        let config = PrometheusConfig::new().with_pushgateway(
+            "http://localhost:9091",
+            "llm_bench",
+            HashMap::new(),
+        );
+
+        assert!(config.pushgateway.is_some());
+        assert_eq!(config.pushgateway.unwrap().job, "llm_bench");
+    }
+
+    #[test]
+    fn test_render_counter() {
+        let mut registry = HashMap::new();
+        let metric = Metric::new("llm_requests_total", MetricType::Counter, MetricValue::Counter(5))
+            .with_label("provider", "openai")
+            .with_help("Total requests");
+        registry.insert("key".to_string(), metric);
+
+        let rendered = render_registry(&registry);
+        assert!(rendered.contains("# HELP llm_requests_total Total requests"));
+        assert!(rendered.contains("# TYPE llm_requests_total counter"));
+        assert!(rendered.contains("llm_requests_total{provider=\"openai\"} 5"));
+    }
+
+    #[tokio::test]
+    async fn test_exporter_creation() {
+        let event_bus = Arc::new(EventBus::new());
+        let exporter = PrometheusExporter::new(PrometheusConfig::new(), event_bus);
+        assert!(exporter.is_ok());
+    }
+
+    #[test]
+    fn test_render_histogram_exemplar() {
+        use crate::monitoring::metrics::LatencyMetric;
+
+        let mut registry = HashMap::new();
+        let metric = LatencyMetric::new("openai", "gpt-4", 1.5)
+            .with_trace_id("req_42")
+            .to_metric();
+        registry.insert("key".to_string(), metric);
+
+        let rendered = render_registry(&registry);
+        assert!(rendered.contains("# {trace_id=\"req_42\"} 1.5"));
+    }
+
+    #[test]
+    fn test_accumulate_metric_sums_counters() {
+        let mut existing = Metric::new("llm_requests_total", MetricType::Counter, MetricValue::Counter(5));
+        let incoming = Metric::new("llm_requests_total", MetricType::Counter, MetricValue::Counter(3));
+
+        accumulate_metric(&mut existing, &incoming);
+
+        assert!(matches!(existing.value, MetricValue::Counter(8)));
+    }
+
+    #[test]
+    fn test_accumulate_metric_replaces_gauges() {
+        let mut existing = Metric::new("llm_active_requests", MetricType::Gauge, MetricValue::Gauge(5.0));
+        let incoming = Metric::new("llm_active_requests", MetricType::Gauge, MetricValue::Gauge(2.0));
+
+        accumulate_metric(&mut existing, &incoming);
+
+        assert!(matches!(existing.value, MetricValue::Gauge(v) if v == 2.0));
+    }
+
+    #[test]
+    fn test_merge_histogram_sums_matching_buckets_and_appends_new_ones() {
+        let mut total = HistogramValue {
+            buckets: vec![HistogramBucket { le: 1.0, count: 2, exemplar: None }],
+            count: 2,
+            sum: 1.5,
+        };
+        let delta = HistogramValue {
+            buckets: vec![
+                HistogramBucket { le: 1.0, count: 3, exemplar: None },
+                HistogramBucket { le: 5.0, count: 1, exemplar: None },
+            ],
+            count: 4,
+            sum: 2.5,
+        };
+
+        merge_histogram(&mut total, &delta);
+
+        assert_eq!(total.count, 6);
+        assert_eq!(total.sum, 4.0);
+        assert_eq!(total.buckets.len(), 2);
+        assert_eq!(total.buckets[0].count, 5);
+        assert_eq!(total.buckets[1].count, 1);
+    }
+}
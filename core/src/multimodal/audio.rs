@@ -11,6 +11,8 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 use anyhow::{Context, Result};
 
+use super::types::{base64_decoded_len, MediaLimitViolation, MediaLimits};
+
 /// Audio format types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -284,6 +286,54 @@ impl AudioInput {
             }
         }
     }
+
+    /// Returns this input's metadata, if known.
+    pub fn metadata(&self) -> Option<&AudioMetadata> {
+        match self {
+            AudioInput::Base64 { metadata, .. }
+            | AudioInput::Url { metadata, .. }
+            | AudioInput::Path { metadata, .. } => metadata.as_ref(),
+        }
+    }
+
+    /// Validates this input against declared ingest limits.
+    ///
+    /// File size is checked via decode-length for `Base64` payloads, or
+    /// the probed size otherwise. Duration and format checks only run
+    /// when metadata is available. Every violated limit is returned, not
+    /// just the first, so callers can report a complete picture.
+    pub fn validate_against(&self, limits: &MediaLimits) -> Result<(), Vec<MediaLimitViolation>> {
+        let mut violations = Vec::new();
+
+        let size_bytes = match self {
+            AudioInput::Base64 { data, .. } => Some(base64_decoded_len(data)),
+            _ => self.metadata().map(|metadata| metadata.size_bytes as u64),
+        };
+        if let (Some(actual), Some(limit)) = (size_bytes, limits.max_file_size_bytes) {
+            if actual > limit {
+                violations.push(MediaLimitViolation::FileTooLarge { actual, limit });
+            }
+        }
+
+        if let Some(metadata) = self.metadata() {
+            if let (Some(limit), Some(duration)) = (limits.max_duration_secs, metadata.duration) {
+                let actual = duration.to_seconds();
+                if actual > limit {
+                    violations.push(MediaLimitViolation::DurationExceeded { actual, limit });
+                }
+            }
+            let format = format!("{:?}", metadata.format);
+            if !limits.allowed_formats.is_empty() && !limits.allowed_formats.contains(&format) {
+                violations.push(MediaLimitViolation::DisallowedFormat { format });
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
 }
 
 /// Audio output from generation/transcription models
@@ -492,4 +542,58 @@ mod tests {
         assert!(!options.timestamps);
         assert!(!options.word_timestamps);
     }
+
+    #[test]
+    fn test_validate_against_passes_with_no_limits() {
+        let input = AudioInput::from_base64("abc123".to_string(), AudioFormat::Mp3);
+        assert!(input.validate_against(&MediaLimits::default()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_against_rejects_too_long_duration() {
+        let input = AudioInput::Path {
+            path: PathBuf::from("long.wav"),
+            metadata: Some(AudioMetadata {
+                format: AudioFormat::Wav,
+                sample_rate: None,
+                channels: None,
+                bit_depth: None,
+                duration: Some(AudioDuration::from_seconds(120.0)),
+                bitrate: None,
+                size_bytes: 10,
+                title: None,
+            }),
+        };
+        let limits = MediaLimits {
+            max_duration_secs: Some(60.0),
+            ..Default::default()
+        };
+
+        let violations = input.validate_against(&limits).unwrap_err();
+        assert!(matches!(violations[0], MediaLimitViolation::DurationExceeded { .. }));
+    }
+
+    #[test]
+    fn test_validate_against_rejects_disallowed_format() {
+        let input = AudioInput::Path {
+            path: PathBuf::from("clip.flac"),
+            metadata: Some(AudioMetadata {
+                format: AudioFormat::Flac,
+                sample_rate: None,
+                channels: None,
+                bit_depth: None,
+                duration: None,
+                bitrate: None,
+                size_bytes: 10,
+                title: None,
+            }),
+        };
+        let limits = MediaLimits {
+            allowed_formats: vec!["Mp3".to_string(), "Wav".to_string()],
+            ..Default::default()
+        };
+
+        let violations = input.validate_against(&limits).unwrap_err();
+        assert!(matches!(violations[0], MediaLimitViolation::DisallowedFormat { .. }));
+    }
 }
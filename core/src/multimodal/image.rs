@@ -10,6 +10,8 @@ use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 
+use super::types::{base64_decoded_len, MediaLimitViolation, MediaLimits};
+
 /// Image format types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -254,6 +256,65 @@ impl ImageInput {
             }
         }
     }
+
+    /// Returns this input's metadata, if known.
+    pub fn metadata(&self) -> Option<&ImageMetadata> {
+        match self {
+            ImageInput::Base64 { metadata, .. }
+            | ImageInput::Url { metadata, .. }
+            | ImageInput::Path { metadata, .. } => metadata.as_ref(),
+        }
+    }
+
+    /// Validates this input against declared ingest limits.
+    ///
+    /// File size is checked via decode-length for `Base64` payloads, or
+    /// the probed size otherwise. Dimension and format checks only run
+    /// when metadata is available. Every violated limit is returned, not
+    /// just the first, so callers can report a complete picture.
+    pub fn validate_against(&self, limits: &MediaLimits) -> Result<(), Vec<MediaLimitViolation>> {
+        let mut violations = Vec::new();
+
+        let size_bytes = match self {
+            ImageInput::Base64 { data, .. } => Some(base64_decoded_len(data)),
+            _ => self.metadata().map(|metadata| metadata.size_bytes as u64),
+        };
+        if let (Some(actual), Some(limit)) = (size_bytes, limits.max_file_size_bytes) {
+            if actual > limit {
+                violations.push(MediaLimitViolation::FileTooLarge { actual, limit });
+            }
+        }
+
+        if let Some(metadata) = self.metadata() {
+            let dimensions = metadata.dimensions;
+            if let Some(limit) = limits.max_width {
+                if dimensions.width > limit {
+                    violations.push(MediaLimitViolation::WidthExceeded { actual: dimensions.width, limit });
+                }
+            }
+            if let Some(limit) = limits.max_height {
+                if dimensions.height > limit {
+                    violations.push(MediaLimitViolation::HeightExceeded { actual: dimensions.height, limit });
+                }
+            }
+            if let Some(limit) = limits.max_area {
+                let area = dimensions.pixels();
+                if area > limit {
+                    violations.push(MediaLimitViolation::AreaExceeded { actual: area, limit });
+                }
+            }
+            let format = format!("{:?}", metadata.format);
+            if !limits.allowed_formats.is_empty() && !limits.allowed_formats.contains(&format) {
+                violations.push(MediaLimitViolation::DisallowedFormat { format });
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
 }
 
 /// Image output from generation models
@@ -413,4 +474,44 @@ mod tests {
         assert_eq!(output.format, ImageFormat::Png);
         assert_eq!(output.data, "base64data");
     }
+
+    #[test]
+    fn test_validate_against_passes_with_no_limits() {
+        let input = ImageInput::from_base64("abc123".to_string(), ImageFormat::Png);
+        assert!(input.validate_against(&MediaLimits::default()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_against_rejects_oversized_base64() {
+        let input = ImageInput::from_base64("aGVsbG8h".to_string(), ImageFormat::Png); // decodes to 6 bytes
+        let limits = MediaLimits {
+            max_file_size_bytes: Some(5),
+            ..Default::default()
+        };
+
+        let violations = input.validate_against(&limits).unwrap_err();
+        assert!(matches!(violations[0], MediaLimitViolation::FileTooLarge { actual: 6, limit: 5 }));
+    }
+
+    #[test]
+    fn test_validate_against_checks_dimensions_and_format() {
+        let input = ImageInput::Path {
+            path: PathBuf::from("big.bmp"),
+            metadata: Some(ImageMetadata {
+                format: ImageFormat::Bmp,
+                dimensions: ImageDimensions::new(4000, 3000),
+                size_bytes: 10,
+                description: None,
+            }),
+        };
+        let limits = MediaLimits {
+            max_width: Some(1920),
+            allowed_formats: vec!["Jpeg".to_string(), "Png".to_string()],
+            ..Default::default()
+        };
+
+        let violations = input.validate_against(&limits).unwrap_err();
+        assert!(violations.iter().any(|v| matches!(v, MediaLimitViolation::WidthExceeded { .. })));
+        assert!(violations.iter().any(|v| matches!(v, MediaLimitViolation::DisallowedFormat { .. })));
+    }
 }
@@ -70,10 +70,15 @@ pub mod video;
 pub mod evaluation;
 pub mod datasets;
 
+/// Shells out to `ffmpeg` to re-encode video, so it's opt-in behind a
+/// feature flag rather than a hard dependency of every build.
+#[cfg(feature = "video-transcode")]
+pub mod transcode;
+
 // Re-export commonly used types
 pub use types::{
     MediaType, MultiModalRequest, MultiModalResponse, MultiModalContent,
-    ContentPart, ImagePart, AudioPart, VideoPart, TextPart,
+    ContentPart, ImagePart, AudioPart, VideoPart, TextPart, MediaLimitViolation, MediaLimits,
 };
 
 pub use image::{
@@ -88,9 +93,12 @@ pub use audio::{
 
 pub use video::{
     VideoInput, VideoOutput, VideoFormat, VideoCodec,
-    VideoMetadata, VideoFrame,
+    VideoMetadata, VideoFrame, FrameSamplingOptions, FrameSamplingMode, VariantSelector,
 };
 
+#[cfg(feature = "video-transcode")]
+pub use transcode::{TranscodeSpec, TranscodeError, transcode, transcode_output};
+
 pub use evaluation::{
     VisionEvaluator, AudioEvaluator, MultiModalEvaluator,
     VisionMetrics, AudioMetrics, MultiModalMetrics,
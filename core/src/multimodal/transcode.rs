@@ -0,0 +1,395 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Video transcoding and normalization to a target format/codec.
+//!
+//! Providers with strict input requirements (a specific container, codec,
+//! resolution, or bitrate ceiling) need consistent media regardless of what
+//! a test case originally attached. This module builds an `ffmpeg` command
+//! from a declarative [`TranscodeSpec`] and produces a new `VideoInput`/
+//! `VideoOutput` matching it, with freshly probed metadata.
+//!
+//! Requires `ffmpeg` to be installed and on `PATH`.
+
+use anyhow::{Context, Result};
+use thiserror::Error;
+
+use super::audio::AudioCodec;
+use super::image::ImageDimensions;
+use super::video::{VideoCodec, VideoFormat, VideoInput, VideoMetadata, VideoOutput};
+
+/// Declarative transcode target, modeled after standard media-service
+/// encode descriptors: a container, a video codec, and optional
+/// bitrate/frame-rate/scale ceilings plus an audio codec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscodeSpec {
+    /// Target container format
+    pub format: VideoFormat,
+    /// Target video codec
+    pub video_codec: VideoCodec,
+    /// Maximum video bitrate in kbps (omit to let the encoder choose)
+    pub max_bitrate_kbps: Option<u32>,
+    /// Target frame rate (omit to keep the source's)
+    pub target_fps: Option<f32>,
+    /// Target frame dimensions (omit to keep the source's)
+    pub scale: Option<ImageDimensions>,
+    /// Target audio codec (omit to drop/copy audio as `ffmpeg` chooses)
+    pub audio: Option<AudioCodec>,
+}
+
+impl TranscodeSpec {
+    /// Creates a spec targeting `format`/`video_codec` with no other constraints.
+    pub fn new(format: VideoFormat, video_codec: VideoCodec) -> Self {
+        Self {
+            format,
+            video_codec,
+            max_bitrate_kbps: None,
+            target_fps: None,
+            scale: None,
+            audio: None,
+        }
+    }
+
+    /// Caps the output video bitrate.
+    pub fn with_max_bitrate_kbps(mut self, max_bitrate_kbps: u32) -> Self {
+        self.max_bitrate_kbps = Some(max_bitrate_kbps);
+        self
+    }
+
+    /// Sets the target frame rate.
+    pub fn with_target_fps(mut self, target_fps: f32) -> Self {
+        self.target_fps = Some(target_fps);
+        self
+    }
+
+    /// Sets the target frame dimensions.
+    pub fn with_scale(mut self, scale: ImageDimensions) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Sets the target audio codec.
+    pub fn with_audio(mut self, audio: AudioCodec) -> Self {
+        self.audio = Some(audio);
+        self
+    }
+
+    /// Returns `true` if `metadata` already satisfies this spec, letting
+    /// `transcode` take a no-op fast path.
+    fn matches(&self, metadata: &VideoMetadata) -> bool {
+        if metadata.format != self.format {
+            return false;
+        }
+
+        match metadata.codec {
+            Some(codec) if codec == self.video_codec => {}
+            // Codec unknown or mismatched - can't prove a match, re-encode.
+            _ => return false,
+        }
+
+        if let Some(target_fps) = self.target_fps {
+            if (metadata.fps - target_fps).abs() > 0.01 {
+                return false;
+            }
+        }
+
+        if let Some(scale) = self.scale {
+            if metadata.dimensions != scale {
+                return false;
+            }
+        }
+
+        if let Some(max_bitrate_kbps) = self.max_bitrate_kbps {
+            match metadata.bitrate {
+                Some(bitrate) if bitrate <= max_bitrate_kbps => {}
+                // Bitrate unknown or over the ceiling - re-encode.
+                _ => return false,
+            }
+        }
+
+        // Metadata records no audio codec, so a requested one can't be proven.
+        if self.audio.is_some() {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// Errors specific to transcoding.
+#[derive(Debug, Error)]
+pub enum TranscodeError {
+    /// The requested video codec cannot be muxed into the requested container.
+    #[error("{codec:?} is not supported in a {format:?} container")]
+    UnsupportedCombination {
+        /// The requested video codec
+        codec: VideoCodec,
+        /// The requested container format
+        format: VideoFormat,
+    },
+    /// Running or reading the output of `ffmpeg` failed.
+    #[error(transparent)]
+    Ffmpeg(#[from] anyhow::Error),
+}
+
+/// Maps a `VideoCodec` to the `ffmpeg` encoder name used to produce it.
+fn encoder_name(codec: VideoCodec) -> &'static str {
+    match codec {
+        VideoCodec::H264 => "libx264",
+        VideoCodec::H265 => "libx265",
+        VideoCodec::Vp8 => "libvpx",
+        VideoCodec::Vp9 => "libvpx-vp9",
+        VideoCodec::Av1 => "libaom-av1",
+    }
+}
+
+/// Maps an `AudioCodec` to the `ffmpeg` encoder name used to produce it.
+fn audio_encoder_name(codec: AudioCodec) -> &'static str {
+    match codec {
+        AudioCodec::Pcm => "pcm_s16le",
+        AudioCodec::Mp3 => "libmp3lame",
+        AudioCodec::Aac => "aac",
+        AudioCodec::Opus => "libopus",
+        AudioCodec::Flac => "flac",
+        AudioCodec::Vorbis => "libvorbis",
+    }
+}
+
+/// Maps a `VideoFormat` to the file extension `ffmpeg` expects for its container.
+fn container_extension(format: VideoFormat) -> &'static str {
+    match format {
+        VideoFormat::Mp4 => "mp4",
+        VideoFormat::WebM => "webm",
+        VideoFormat::Avi => "avi",
+        VideoFormat::Mov => "mov",
+        VideoFormat::Mkv => "mkv",
+    }
+}
+
+/// Rejects container/codec combinations `ffmpeg` can't actually mux (e.g.
+/// VP9 in a MOV container), before spending time invoking it.
+fn check_supported(format: VideoFormat, codec: VideoCodec) -> Result<(), TranscodeError> {
+    let supported = match format {
+        VideoFormat::Mp4 => matches!(codec, VideoCodec::H264 | VideoCodec::H265 | VideoCodec::Av1),
+        VideoFormat::WebM => matches!(codec, VideoCodec::Vp8 | VideoCodec::Vp9 | VideoCodec::Av1),
+        VideoFormat::Mov => matches!(codec, VideoCodec::H264 | VideoCodec::H265),
+        VideoFormat::Avi => matches!(codec, VideoCodec::H264),
+        VideoFormat::Mkv => true, // Matroska muxes virtually any codec
+    };
+
+    if supported {
+        Ok(())
+    } else {
+        Err(TranscodeError::UnsupportedCombination { codec, format })
+    }
+}
+
+/// Builds and runs the `ffmpeg` command that re-encodes `input_path` to
+/// `output_path` per `spec`.
+async fn run_ffmpeg(input_path: &str, output_path: &std::path::Path, spec: &TranscodeSpec) -> Result<()> {
+    let mut command = tokio::process::Command::new("ffmpeg");
+    command.args(["-v", "error", "-y", "-i", input_path]);
+    command.args(["-c:v", encoder_name(spec.video_codec)]);
+
+    if let Some(max_bitrate_kbps) = spec.max_bitrate_kbps {
+        command.args(["-b:v", &format!("{}k", max_bitrate_kbps)]);
+    }
+    if let Some(target_fps) = spec.target_fps {
+        command.args(["-r", &target_fps.to_string()]);
+    }
+    if let Some(scale) = spec.scale {
+        command.args(["-vf", &format!("scale={}:{}", scale.width, scale.height)]);
+    }
+    if let Some(audio) = spec.audio {
+        command.args(["-c:a", audio_encoder_name(audio)]);
+    }
+
+    command.arg(output_path);
+
+    let output = command
+        .output()
+        .await
+        .context("Failed to execute ffmpeg - is it installed and on PATH?")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "ffmpeg exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    Ok(())
+}
+
+/// Transcodes `source` (a `Path` or `Url` input — `Base64` and `Hls` inputs
+/// must be decoded/resolved first) into a new `VideoInput::Path` matching
+/// `spec`.
+///
+/// Takes a no-op fast path, returning `source` unchanged, when its probed
+/// metadata already satisfies `spec` - no `ffmpeg` invocation needed.
+pub async fn transcode(source: &VideoInput, spec: &TranscodeSpec) -> Result<VideoInput, TranscodeError> {
+    check_supported(spec.format, spec.video_codec)?;
+
+    if let Some(metadata) = source.metadata() {
+        if spec.matches(metadata) {
+            return Ok(source.clone());
+        }
+    }
+
+    let input_path = match source {
+        VideoInput::Path { path, .. } => path.to_string_lossy().to_string(),
+        VideoInput::Url { url, .. } => url.clone(),
+        _ => {
+            return Err(TranscodeError::Ffmpeg(anyhow::anyhow!(
+                "transcode requires a Path or Url video input; resolve Base64/Hls inputs first"
+            )))
+        }
+    };
+
+    let output_dir = tempfile::tempdir().context("Failed to create temp directory for transcoding")?;
+    let output_path = output_dir.path().join(format!("transcoded.{}", container_extension(spec.format)));
+
+    run_ffmpeg(&input_path, &output_path, spec).await?;
+
+    // `VideoInput::Path` needs the file to live on disk beyond this call, so
+    // persist it rather than letting `output_dir` delete it on drop.
+    let output_dir = output_dir.into_path();
+    let output_path = output_dir.join(output_path.file_name().expect("output_path always has a file name"));
+
+    VideoInput::from_path(&output_path).await.map_err(TranscodeError::from)
+}
+
+/// Re-encodes a generated `VideoOutput` to match `spec`, returning a new
+/// `VideoOutput` with freshly probed metadata.
+///
+/// Takes the same no-op fast path as [`transcode`] when `source.metadata`
+/// already satisfies `spec`.
+pub async fn transcode_output(source: &VideoOutput, spec: &TranscodeSpec) -> Result<VideoOutput, TranscodeError> {
+    check_supported(spec.format, spec.video_codec)?;
+
+    if let Some(metadata) = source.metadata.as_ref() {
+        if spec.matches(metadata) {
+            return Ok(source.clone());
+        }
+    }
+
+    let input_dir = tempfile::tempdir().context("Failed to create temp directory for transcoding")?;
+    let input_path = input_dir.path().join(format!("source.{}", container_extension(source.format)));
+
+    if source.data.starts_with("http://") || source.data.starts_with("https://") {
+        let response = reqwest::get(&source.data).await.context("Failed to fetch source video")?;
+        let bytes = response.bytes().await.context("Failed to read source video bytes")?;
+        tokio::fs::write(&input_path, &bytes).await.context("Failed to write source video to temp file")?;
+    } else {
+        use base64::{engine::general_purpose, Engine as _};
+        let bytes = general_purpose::STANDARD
+            .decode(&source.data)
+            .context("Failed to decode base64 source video")?;
+        tokio::fs::write(&input_path, &bytes).await.context("Failed to write source video to temp file")?;
+    }
+
+    let source_input = VideoInput::from_path(&input_path).await?;
+    let transcoded = transcode(&source_input, spec).await?;
+
+    let (path, metadata) = match transcoded {
+        VideoInput::Path { path, metadata } => (path, metadata),
+        _ => unreachable!("transcode always returns a Path input"),
+    };
+
+    use base64::{engine::general_purpose, Engine as _};
+    let bytes = tokio::fs::read(&path).await.context("Failed to read transcoded video")?;
+    let data = general_purpose::STANDARD.encode(bytes);
+
+    Ok(VideoOutput { data, format: spec.format, metadata })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_metadata(format: VideoFormat, codec: VideoCodec) -> VideoMetadata {
+        VideoMetadata {
+            format,
+            dimensions: ImageDimensions::new(1280, 720),
+            fps: 30.0,
+            duration: crate::multimodal::audio::AudioDuration::new(5, 0),
+            codec: Some(codec),
+            bitrate: None,
+            size_bytes: 10,
+            has_audio: false,
+        }
+    }
+
+    #[test]
+    fn test_transcode_spec_builder() {
+        let spec = TranscodeSpec::new(VideoFormat::Mp4, VideoCodec::H264)
+            .with_max_bitrate_kbps(2000)
+            .with_target_fps(30.0)
+            .with_scale(ImageDimensions::new(1280, 720))
+            .with_audio(AudioCodec::Aac);
+
+        assert_eq!(spec.max_bitrate_kbps, Some(2000));
+        assert_eq!(spec.audio, Some(AudioCodec::Aac));
+    }
+
+    #[test]
+    fn test_spec_matches_identical_metadata() {
+        let spec = TranscodeSpec::new(VideoFormat::Mp4, VideoCodec::H264);
+        let metadata = sample_metadata(VideoFormat::Mp4, VideoCodec::H264);
+        assert!(spec.matches(&metadata));
+    }
+
+    #[test]
+    fn test_spec_does_not_match_different_codec() {
+        let spec = TranscodeSpec::new(VideoFormat::Mp4, VideoCodec::H264);
+        let metadata = sample_metadata(VideoFormat::Mp4, VideoCodec::Vp9);
+        assert!(!spec.matches(&metadata));
+    }
+
+    #[test]
+    fn test_check_supported_rejects_vp9_in_mov() {
+        let result = check_supported(VideoFormat::Mov, VideoCodec::Vp9);
+        assert!(matches!(result, Err(TranscodeError::UnsupportedCombination { .. })));
+    }
+
+    #[test]
+    fn test_check_supported_allows_h264_in_mp4() {
+        assert!(check_supported(VideoFormat::Mp4, VideoCodec::H264).is_ok());
+    }
+
+    #[test]
+    fn test_encoder_names() {
+        assert_eq!(encoder_name(VideoCodec::H264), "libx264");
+        assert_eq!(encoder_name(VideoCodec::H265), "libx265");
+        assert_eq!(encoder_name(VideoCodec::Vp9), "libvpx-vp9");
+        assert_eq!(encoder_name(VideoCodec::Av1), "libaom-av1");
+    }
+
+    #[tokio::test]
+    async fn test_transcode_rejects_unsupported_combination() {
+        let source = VideoInput::from_url("https://example.com/video.mov");
+        let spec = TranscodeSpec::new(VideoFormat::Mov, VideoCodec::Vp9);
+
+        let result = transcode(&source, &spec).await;
+        assert!(matches!(result, Err(TranscodeError::UnsupportedCombination { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_transcode_fast_path_when_already_matching() {
+        let source = VideoInput::Path {
+            path: std::path::PathBuf::from("clip.mp4"),
+            metadata: Some(sample_metadata(VideoFormat::Mp4, VideoCodec::H264)),
+        };
+        let spec = TranscodeSpec::new(VideoFormat::Mp4, VideoCodec::H264);
+
+        let result = transcode(&source, &spec).await.unwrap();
+        match result {
+            VideoInput::Path { path, .. } => assert_eq!(path, std::path::PathBuf::from("clip.mp4")),
+            _ => panic!("expected Path variant"),
+        }
+    }
+}
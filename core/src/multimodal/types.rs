@@ -8,6 +8,7 @@
 
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use thiserror::Error;
 
 use super::image::{ImageInput, ImageOutput};
 use super::audio::{AudioInput, AudioOutput};
@@ -532,6 +533,114 @@ impl std::fmt::Display for FinishReason {
     }
 }
 
+/// A specific ingest limit violated by a media input.
+///
+/// Returned by `validate_against` on [`ImageInput`], [`AudioInput`], and
+/// [`VideoInput`] so callers can report exactly which constraint failed
+/// instead of a single pass/fail bit.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum MediaLimitViolation {
+    /// The media's file size exceeds `max_file_size_bytes`.
+    #[error("file size {actual} bytes exceeds the limit of {limit} bytes")]
+    FileTooLarge {
+        /// Actual size in bytes
+        actual: u64,
+        /// Configured limit in bytes
+        limit: u64,
+    },
+    /// The media's width exceeds `max_width`.
+    #[error("width {actual}px exceeds the limit of {limit}px")]
+    WidthExceeded {
+        /// Actual width in pixels
+        actual: u32,
+        /// Configured limit in pixels
+        limit: u32,
+    },
+    /// The media's height exceeds `max_height`.
+    #[error("height {actual}px exceeds the limit of {limit}px")]
+    HeightExceeded {
+        /// Actual height in pixels
+        actual: u32,
+        /// Configured limit in pixels
+        limit: u32,
+    },
+    /// The media's area (width * height) exceeds `max_area`.
+    #[error("area {actual}px^2 exceeds the limit of {limit}px^2")]
+    AreaExceeded {
+        /// Actual area in square pixels
+        actual: u64,
+        /// Configured limit in square pixels
+        limit: u64,
+    },
+    /// The media's duration exceeds `max_duration_secs`.
+    #[error("duration {actual:.2}s exceeds the limit of {limit:.2}s")]
+    DurationExceeded {
+        /// Actual duration in seconds
+        actual: f64,
+        /// Configured limit in seconds
+        limit: f64,
+    },
+    /// The media's format is not in the configured allow-list.
+    #[error("format {format} is not in the allowed list")]
+    DisallowedFormat {
+        /// The rejected format, formatted for display
+        format: String,
+    },
+    /// The media's codec is not in the configured allow-list.
+    #[error("codec {codec} is not in the allowed list")]
+    DisallowedCodec {
+        /// The rejected codec, formatted for display
+        codec: String,
+    },
+}
+
+/// Ingest limits shared by [`ImageInput::validate_against`],
+/// [`AudioInput::validate_against`], and [`VideoInput::validate_against`].
+///
+/// Every field is optional (or an empty list), meaning "unconstrained".
+/// Fields that don't apply to a modality are simply ignored by its
+/// `validate_against` (e.g. `max_duration_secs` for images). Allowed
+/// formats/codecs are matched against each modality's own format/codec enum
+/// via its `{:?}` debug representation, the same representation
+/// [`MediaLimitViolation::DisallowedFormat`]/[`MediaLimitViolation::DisallowedCodec`]
+/// report, since a single limits type can't carry per-modality enum types.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MediaLimits {
+    /// Maximum file size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_file_size_bytes: Option<u64>,
+    /// Maximum width in pixels (images, video)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_width: Option<u32>,
+    /// Maximum height in pixels (images, video)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_height: Option<u32>,
+    /// Maximum area (width * height) in pixels (images, video)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_area: Option<u64>,
+    /// Maximum duration in seconds (audio, video)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_duration_secs: Option<f64>,
+    /// Allowed formats, by their `{:?}` debug representation (e.g. `"Mp4"`)
+    /// - empty means no restriction
+    #[serde(default)]
+    pub allowed_formats: Vec<String>,
+    /// Allowed codecs, by their `{:?}` debug representation (e.g. `"H264"`)
+    /// - empty means no restriction (video only)
+    #[serde(default)]
+    pub allowed_codecs: Vec<String>,
+}
+
+/// Estimates the decoded byte length of a base64 string without allocating
+/// a buffer, so `validate_against` can size-check `Base64` media payloads
+/// up front.
+pub(crate) fn base64_decoded_len(data: &str) -> u64 {
+    let trimmed = data.trim_end_matches('=');
+    let padding = (data.len() - trimmed.len()) as u64;
+    let groups = data.len() as u64 / 4;
+    (groups * 3).saturating_sub(padding)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -579,6 +688,20 @@ mod tests {
         assert!(request.is_text_only());
     }
 
+    #[test]
+    fn test_base64_decoded_len() {
+        // "aGVsbG8=" decodes to "hello" (5 bytes), "aGVsbG8h" (no padding) to "hello!" (6 bytes)
+        assert_eq!(base64_decoded_len("aGVsbG8="), 5);
+        assert_eq!(base64_decoded_len("aGVsbG8h"), 6);
+    }
+
+    #[test]
+    fn test_media_limit_violation_display() {
+        let error = MediaLimitViolation::FileTooLarge { actual: 200, limit: 100 };
+        assert!(error.to_string().contains("200 bytes"));
+        assert!(error.to_string().contains("100 bytes"));
+    }
+
     #[test]
     fn test_multimodal_usage() {
         let usage = MultiModalUsage::new(100, 50)
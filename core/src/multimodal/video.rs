@@ -12,6 +12,7 @@ use anyhow::{Context, Result};
 
 use super::image::{ImageDimensions, ImageFormat};
 use super::audio::AudioDuration;
+use super::types::{base64_decoded_len, MediaLimitViolation, MediaLimits};
 
 /// Video format types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -171,6 +172,17 @@ pub enum VideoInput {
         #[serde(skip_serializing_if = "Option::is_none")]
         metadata: Option<VideoMetadata>,
     },
+    /// HTTP Live Streaming (HLS) master playlist
+    Hls {
+        /// URL of the master `.m3u8` playlist
+        master_url: String,
+        /// How to pick a variant stream out of the master playlist
+        #[serde(default)]
+        selector: VariantSelector,
+        /// Optional metadata
+        #[serde(skip_serializing_if = "Option::is_none")]
+        metadata: Option<VideoMetadata>,
+    },
 }
 
 impl VideoInput {
@@ -181,6 +193,14 @@ impl VideoInput {
         }
     }
 
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't exist or its format can't be
+    /// determined from its extension. When the `video-probe` feature is
+    /// enabled, also returns an error if `ffprobe` is missing or fails to
+    /// probe the file, rather than silently falling back to placeholder
+    /// metadata - callers shouldn't see plausible-looking zeroed dimensions
+    /// for a file ffprobe couldn't actually read.
     pub async fn from_path(path: impl AsRef<Path>) -> Result<Self> {
         let path = path.as_ref();
 
@@ -193,21 +213,18 @@ impl VideoInput {
             .and_then(VideoFormat::from_extension)
             .context("Could not determine video format from file extension")?;
 
-        let metadata = tokio::fs::metadata(path).await?;
-        let size_bytes = metadata.len() as usize;
+        #[cfg(feature = "video-probe")]
+        let metadata = {
+            let file_metadata = tokio::fs::metadata(path).await?;
+            let size_bytes = file_metadata.len() as usize;
+            Some(probe_metadata(path, format, size_bytes).await?)
+        };
+        #[cfg(not(feature = "video-probe"))]
+        let metadata = None;
 
         Ok(Self::Path {
             path: path.to_path_buf(),
-            metadata: Some(VideoMetadata {
-                format,
-                dimensions: ImageDimensions::new(0, 0),
-                fps: 0.0,
-                duration: AudioDuration::new(0, 0),
-                codec: None,
-                bitrate: None,
-                size_bytes,
-                has_audio: false,
-            }),
+            metadata,
         })
     }
 
@@ -218,6 +235,487 @@ impl VideoInput {
             metadata: None,
         }
     }
+
+    /// Creates a video input from an HLS master playlist URL.
+    pub fn from_hls(master_url: impl Into<String>, selector: VariantSelector) -> Self {
+        Self::Hls {
+            master_url: master_url.into(),
+            selector,
+            metadata: None,
+        }
+    }
+
+    /// Fetches the master playlist (for an `Hls` input) and resolves the
+    /// URL of the variant stream chosen by its selector, along with
+    /// `VideoMetadata` derived from that variant's playlist attributes
+    /// (resolution, frame rate, codec, bandwidth-derived bitrate).
+    ///
+    /// Returns an error if called on a non-`Hls` input, if the playlist
+    /// can't be fetched, or if it contains no variant streams.
+    pub async fn resolve_hls_variant(&self) -> Result<(String, VideoMetadata)> {
+        let (master_url, selector) = match self {
+            VideoInput::Hls { master_url, selector, .. } => (master_url, *selector),
+            _ => anyhow::bail!("resolve_hls_variant requires an Hls video input"),
+        };
+
+        let response = reqwest::get(master_url)
+            .await
+            .context("Failed to fetch HLS master playlist")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to fetch HLS master playlist: HTTP {}", response.status());
+        }
+
+        let playlist = response.text().await.context("Failed to read HLS master playlist body")?;
+        let variants = parse_hls_variants(&playlist);
+
+        let variant = selector
+            .select_variant(&variants)
+            .context("HLS master playlist contains no variant streams")?;
+
+        Ok((resolve_hls_uri(master_url, &variant.uri), variant_metadata(variant)))
+    }
+
+    /// Samples frames from a `Path` or `Url` video input using `ffmpeg`,
+    /// returning a new `Frames` input. A `Frames` input is returned as-is.
+    ///
+    /// Requires `ffmpeg` (part of the FFmpeg suite) to be installed and on
+    /// `PATH`.
+    pub async fn sample_frames(&self, options: FrameSamplingOptions) -> Result<VideoInput> {
+        let source = match self {
+            VideoInput::Path { path, .. } => path.to_string_lossy().to_string(),
+            VideoInput::Url { url, .. } => url.clone(),
+            VideoInput::Frames { .. } => return Ok(self.clone()),
+            VideoInput::Base64 { .. } => {
+                anyhow::bail!("Frame sampling requires a Path or Url video input, not Base64 data")
+            }
+            VideoInput::Hls { .. } => {
+                anyhow::bail!("Frame sampling requires a Path or Url video input; resolve an Hls input with resolve_hls_variant() first")
+            }
+        };
+
+        let output_dir = tempfile::tempdir().context("Failed to create temp directory for frame sampling")?;
+        let scale_filter = scale_filter(options.max_dimensions);
+
+        let (timestamps, fps_hint) = match &options.mode {
+            FrameSamplingMode::FixedFps(fps) => {
+                let pattern = output_dir.path().join(format!("frame-%05d.{}", frame_extension(options.format)));
+                let mut vf = format!("fps={fps}");
+                if let Some(scale) = &scale_filter {
+                    vf.push(',');
+                    vf.push_str(scale);
+                }
+
+                let mut command = tokio::process::Command::new("ffmpeg");
+                command.args(["-v", "error", "-i", &source, "-vf"]).arg(vf);
+                if let Some(max_frames) = options.max_frames {
+                    command.args(["-frames:v", &max_frames.to_string()]);
+                }
+                command.arg(&pattern);
+                run_ffmpeg(command).await?;
+
+                (None, *fps)
+            }
+            FrameSamplingMode::FixedCount(count) => {
+                let duration = self
+                    .metadata()
+                    .map(|metadata| metadata.duration.to_seconds())
+                    .filter(|duration| *duration > 0.0)
+                    .context("Sampling a fixed frame count requires a video input with known duration metadata")?;
+
+                let timestamps: Vec<f64> = (0..*count)
+                    .map(|index| duration * index as f64 / (*count).max(1) as f64)
+                    .collect();
+                let fps_hint = *count as f32 / duration as f32;
+                sample_frames_at_timestamps(&source, &timestamps, options.format, scale_filter.as_deref(), &output_dir).await?;
+
+                (Some(timestamps), fps_hint)
+            }
+            FrameSamplingMode::Timestamps(timestamps) => {
+                sample_frames_at_timestamps(&source, timestamps, options.format, scale_filter.as_deref(), &output_dir).await?;
+                (Some(timestamps.clone()), 0.0)
+            }
+        };
+
+        let mut frame_paths = Vec::new();
+        let mut entries = tokio::fs::read_dir(output_dir.path())
+            .await
+            .context("Failed to read sampled frame directory")?;
+        while let Some(entry) = entries.next_entry().await? {
+            frame_paths.push(entry.path());
+        }
+        frame_paths.sort();
+
+        use base64::{engine::general_purpose, Engine as _};
+
+        let mut frames = Vec::with_capacity(frame_paths.len());
+        for (index, frame_path) in frame_paths.iter().enumerate() {
+            let bytes = tokio::fs::read(frame_path).await.context("Failed to read sampled frame")?;
+            let data = general_purpose::STANDARD.encode(bytes);
+            let timestamp = timestamps
+                .as_ref()
+                .and_then(|timestamps| timestamps.get(index).copied())
+                .unwrap_or_else(|| index as f64 / fps_hint as f64);
+            frames.push(VideoFrame::new(index as u32, timestamp, data, options.format));
+        }
+
+        Ok(VideoInput::from_frames(frames, fps_hint))
+    }
+
+    /// Returns this input's metadata, if known.
+    pub fn metadata(&self) -> Option<&VideoMetadata> {
+        match self {
+            VideoInput::Base64 { metadata, .. }
+            | VideoInput::Url { metadata, .. }
+            | VideoInput::Path { metadata, .. }
+            | VideoInput::Frames { metadata, .. }
+            | VideoInput::Hls { metadata, .. } => metadata.as_ref(),
+        }
+    }
+
+    /// Validates this input against declared ingest limits.
+    ///
+    /// File size is checked via decode-length for `Base64` payloads, or
+    /// the probed size otherwise. Dimension, format, and codec checks only
+    /// run when metadata is available. Every violated limit is returned,
+    /// not just the first, so callers can report a complete picture per
+    /// test case.
+    pub fn validate_against(&self, limits: &MediaLimits) -> Result<(), Vec<MediaLimitViolation>> {
+        let mut violations = Vec::new();
+
+        let size_bytes = match self {
+            VideoInput::Base64 { data, .. } => Some(base64_decoded_len(data)),
+            _ => self.metadata().map(|metadata| metadata.size_bytes as u64),
+        };
+        if let (Some(actual), Some(limit)) = (size_bytes, limits.max_file_size_bytes) {
+            if actual > limit {
+                violations.push(MediaLimitViolation::FileTooLarge { actual, limit });
+            }
+        }
+
+        if let Some(metadata) = self.metadata() {
+            let dimensions = metadata.dimensions;
+            if let Some(limit) = limits.max_width {
+                if dimensions.width > limit {
+                    violations.push(MediaLimitViolation::WidthExceeded { actual: dimensions.width, limit });
+                }
+            }
+            if let Some(limit) = limits.max_height {
+                if dimensions.height > limit {
+                    violations.push(MediaLimitViolation::HeightExceeded { actual: dimensions.height, limit });
+                }
+            }
+            if let Some(limit) = limits.max_area {
+                let area = dimensions.pixels();
+                if area > limit {
+                    violations.push(MediaLimitViolation::AreaExceeded { actual: area, limit });
+                }
+            }
+            let format = format!("{:?}", metadata.format);
+            if !limits.allowed_formats.is_empty() && !limits.allowed_formats.contains(&format) {
+                violations.push(MediaLimitViolation::DisallowedFormat { format });
+            }
+            if let Some(codec) = metadata.codec {
+                let codec = format!("{:?}", codec);
+                if !limits.allowed_codecs.is_empty() && !limits.allowed_codecs.contains(&codec) {
+                    violations.push(MediaLimitViolation::DisallowedCodec { codec });
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// Strategy for choosing which frames `VideoInput::sample_frames` extracts.
+#[derive(Debug, Clone)]
+pub enum FrameSamplingMode {
+    /// Sample at a fixed rate: this many frames per second of video.
+    FixedFps(f32),
+    /// Extract this many frames total, spread evenly across the video's
+    /// duration. Requires the input to have known metadata (for its
+    /// duration), since evenly spacing frames needs to know how long the
+    /// video is.
+    FixedCount(usize),
+    /// Extract one frame at each of these timestamps (seconds from start).
+    Timestamps(Vec<f64>),
+}
+
+/// Options controlling how `VideoInput::sample_frames` extracts frames.
+#[derive(Debug, Clone)]
+pub struct FrameSamplingOptions {
+    /// How to choose which frames to extract
+    pub mode: FrameSamplingMode,
+    /// Maximum number of frames to extract, regardless of video length.
+    /// Applied only by `FixedFps`; `FixedCount` and `Timestamps` ignore it,
+    /// since their count or timestamp list already bounds the frame count.
+    pub max_frames: Option<usize>,
+    /// Image format to encode sampled frames as
+    pub format: ImageFormat,
+    /// Cap sampled frames to at most these dimensions, preserving aspect
+    /// ratio. `None` samples frames at their native resolution.
+    pub max_dimensions: Option<ImageDimensions>,
+}
+
+impl Default for FrameSamplingOptions {
+    fn default() -> Self {
+        Self {
+            mode: FrameSamplingMode::FixedFps(1.0),
+            max_frames: Some(32),
+            format: ImageFormat::Jpeg,
+            max_dimensions: None,
+        }
+    }
+}
+
+/// Runs an already-configured `ffmpeg` command, erroring with its stderr on
+/// a non-zero exit.
+async fn run_ffmpeg(mut command: tokio::process::Command) -> Result<()> {
+    let output = command
+        .output()
+        .await
+        .context("Failed to execute ffmpeg - is it installed and on PATH?")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "ffmpeg exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    Ok(())
+}
+
+/// Builds an `ffmpeg` `scale` filter that caps frames to `max_dimensions`
+/// while preserving aspect ratio, or `None` if no cap was requested.
+fn scale_filter(max_dimensions: Option<ImageDimensions>) -> Option<String> {
+    max_dimensions.map(|dimensions| {
+        format!(
+            "scale='min({w},iw)':'min({h},ih)':force_original_aspect_ratio=decrease",
+            w = dimensions.width,
+            h = dimensions.height,
+        )
+    })
+}
+
+/// Extracts one frame at each of `timestamps` by running `ffmpeg` once per
+/// timestamp (`-ss <timestamp> -frames:v 1`), writing numbered files into
+/// `output_dir`.
+async fn sample_frames_at_timestamps(
+    source: &str,
+    timestamps: &[f64],
+    format: ImageFormat,
+    scale_filter: Option<&str>,
+    output_dir: &tempfile::TempDir,
+) -> Result<()> {
+    for (index, timestamp) in timestamps.iter().enumerate() {
+        let output_path = output_dir.path().join(format!("frame-{index:05}.{}", frame_extension(format)));
+
+        let mut command = tokio::process::Command::new("ffmpeg");
+        command.args(["-v", "error", "-ss", &timestamp.to_string(), "-i", source, "-frames:v", "1"]);
+        if let Some(scale) = scale_filter {
+            command.args(["-vf", scale]);
+        }
+        command.arg(&output_path);
+
+        run_ffmpeg(command).await?;
+    }
+
+    Ok(())
+}
+
+/// Maps an `ImageFormat` to the file extension `ffmpeg` expects.
+fn frame_extension(format: ImageFormat) -> &'static str {
+    match format {
+        ImageFormat::Jpeg => "jpg",
+        ImageFormat::Png => "png",
+        ImageFormat::WebP => "webp",
+        ImageFormat::Gif => "gif",
+        ImageFormat::Bmp => "bmp",
+    }
+}
+
+/// Strategy for picking a variant stream from an HLS master playlist.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariantSelector {
+    /// The highest-bandwidth variant stream
+    MaxBandwidth,
+    /// The variant with the highest resolution (by pixel count)
+    MaxResolution,
+    /// The variant whose resolution is closest to the given width/height
+    ClosestTo {
+        /// Target width in pixels
+        width: u32,
+        /// Target height in pixels
+        height: u32,
+    },
+    /// The variant whose bandwidth is closest to this exact value
+    ExactBandwidth(u32),
+}
+
+impl Default for VariantSelector {
+    fn default() -> Self {
+        VariantSelector::MaxBandwidth
+    }
+}
+
+impl VariantSelector {
+    /// Picks a variant stream out of `variants` according to this strategy.
+    fn select_variant<'a>(&self, variants: &'a [HlsVariant]) -> Option<&'a HlsVariant> {
+        match self {
+            VariantSelector::MaxBandwidth => variants.iter().max_by_key(|variant| variant.bandwidth),
+            VariantSelector::MaxResolution => variants.iter().max_by_key(|variant| {
+                variant.resolution.map(|(width, height)| width as u64 * height as u64).unwrap_or(0)
+            }),
+            VariantSelector::ClosestTo { width, height } => variants.iter().min_by_key(|variant| {
+                variant
+                    .resolution
+                    .map(|(w, h)| {
+                        let dw = w as i64 - *width as i64;
+                        let dh = h as i64 - *height as i64;
+                        dw * dw + dh * dh
+                    })
+                    .unwrap_or(i64::MAX)
+            }),
+            VariantSelector::ExactBandwidth(target) => {
+                variants.iter().min_by_key(|variant| (variant.bandwidth as i64 - *target as i64).abs())
+            }
+        }
+    }
+}
+
+/// A single variant stream entry parsed from an HLS master playlist.
+#[derive(Debug, Clone, PartialEq)]
+struct HlsVariant {
+    bandwidth: u64,
+    resolution: Option<(u32, u32)>,
+    frame_rate: Option<f32>,
+    codecs: Option<String>,
+    uri: String,
+}
+
+/// Derives best-effort `VideoMetadata` for the chosen HLS variant from its
+/// `#EXT-X-STREAM-INF` attributes. Attributes the playlist doesn't carry
+/// (duration, file size, audio presence) are left at their zero/default
+/// values - callers that need those should probe the resolved segment URI
+/// directly.
+fn variant_metadata(variant: &HlsVariant) -> VideoMetadata {
+    let (width, height) = variant.resolution.unwrap_or((0, 0));
+    let (codec, has_audio) = variant.codecs.as_deref().map(parse_hls_codecs).unwrap_or((None, false));
+
+    VideoMetadata {
+        format: VideoFormat::Mp4,
+        dimensions: ImageDimensions::new(width, height),
+        fps: variant.frame_rate.unwrap_or(0.0),
+        duration: AudioDuration::new(0, 0),
+        codec,
+        bitrate: Some((variant.bandwidth / 1000) as u32),
+        size_bytes: 0,
+        has_audio,
+    }
+}
+
+/// Maps the codec entries in an HLS `CODECS` attribute (e.g.
+/// `"avc1.640028,mp4a.40.2"`) to our `VideoCodec` enum, and reports whether
+/// an audio codec entry was also present.
+fn parse_hls_codecs(raw: &str) -> (Option<VideoCodec>, bool) {
+    let mut codec = None;
+    let mut has_audio = false;
+
+    for token in raw.split(',').map(str::trim) {
+        if token.starts_with("avc1") || token.starts_with("avc3") {
+            codec = codec.or(Some(VideoCodec::H264));
+        } else if token.starts_with("hev1") || token.starts_with("hvc1") {
+            codec = codec.or(Some(VideoCodec::H265));
+        } else if token.starts_with("vp09") {
+            codec = codec.or(Some(VideoCodec::Vp9));
+        } else if token.starts_with("vp08") {
+            codec = codec.or(Some(VideoCodec::Vp8));
+        } else if token.starts_with("av01") {
+            codec = codec.or(Some(VideoCodec::Av1));
+        } else if token.starts_with("mp4a") || token.starts_with("ac-3") || token.starts_with("ec-3") {
+            has_audio = true;
+        }
+    }
+
+    (codec, has_audio)
+}
+
+/// Parses `#EXT-X-STREAM-INF` entries out of an HLS master playlist.
+fn parse_hls_variants(playlist: &str) -> Vec<HlsVariant> {
+    let mut variants = Vec::new();
+    let mut lines = playlist.lines();
+
+    while let Some(line) = lines.next() {
+        let Some(attrs) = line.strip_prefix("#EXT-X-STREAM-INF:") else {
+            continue;
+        };
+
+        let bandwidth = parse_hls_attr(attrs, "BANDWIDTH")
+            .and_then(|value| value.parse::<u64>().ok())
+            .unwrap_or(0);
+
+        let resolution = parse_hls_attr(attrs, "RESOLUTION").and_then(|value| {
+            let (width, height) = value.split_once('x')?;
+            Some((width.parse().ok()?, height.parse().ok()?))
+        });
+
+        let frame_rate = parse_hls_attr(attrs, "FRAME-RATE").and_then(|value| value.parse::<f32>().ok());
+        let codecs = parse_hls_attr(attrs, "CODECS").map(str::to_string);
+
+        let Some(uri) = lines.next().map(str::trim) else {
+            continue;
+        };
+        if uri.is_empty() || uri.starts_with('#') {
+            continue;
+        }
+
+        variants.push(HlsVariant {
+            bandwidth,
+            resolution,
+            frame_rate,
+            codecs,
+            uri: uri.to_string(),
+        });
+    }
+
+    variants
+}
+
+/// Extracts the value of `key` from a comma-separated `EXT-X-STREAM-INF`
+/// attribute list, stripping surrounding quotes.
+fn parse_hls_attr<'a>(attrs: &'a str, key: &str) -> Option<&'a str> {
+    let mut rest = attrs;
+    while let Some((name, after)) = rest.split_once('=') {
+        let (value, next) = match after.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').map(|(value, next)| (value, next.trim_start_matches(',')))?,
+            None => after.split_once(',').unwrap_or((after, "")),
+        };
+        if name.trim() == key {
+            return Some(value.trim());
+        }
+        rest = next;
+    }
+    None
+}
+
+/// Resolves a (possibly relative) variant URI against the master playlist's
+/// URL, matching how HLS clients interpret relative variant paths.
+fn resolve_hls_uri(master_url: &str, variant_uri: &str) -> String {
+    if variant_uri.starts_with("http://") || variant_uri.starts_with("https://") {
+        return variant_uri.to_string();
+    }
+
+    match master_url.rfind('/') {
+        Some(index) => format!("{}/{}", &master_url[..index], variant_uri),
+        None => variant_uri.to_string(),
+    }
 }
 
 /// Video output from generation models
@@ -234,6 +732,134 @@ pub struct VideoOutput {
     pub metadata: Option<VideoMetadata>,
 }
 
+/// Runs `ffprobe` (part of the FFmpeg suite) against a local video file and
+/// parses its JSON output into `VideoMetadata`.
+///
+/// Requires `ffprobe` to be installed and on `PATH`, so this is gated behind
+/// the `video-probe` feature rather than being a hard dependency of every
+/// build (same reasoning as the `video-transcode` gate on [`super::transcode`]).
+/// Returns a distinct, actionable error when the binary itself is missing,
+/// rather than letting callers treat a probe failure as "metadata
+/// unavailable" and fall back to bogus placeholder values.
+#[cfg(feature = "video-probe")]
+async fn probe_metadata(path: &Path, format: VideoFormat, size_bytes: usize) -> Result<VideoMetadata> {
+    let output = tokio::process::Command::new("ffprobe")
+        .args(["-v", "quiet", "-print_format", "json", "-show_format", "-show_streams"])
+        .arg(path)
+        .output()
+        .await
+        .map_err(|error| {
+            if error.kind() == std::io::ErrorKind::NotFound {
+                anyhow::anyhow!(
+                    "ffprobe is not installed or not on PATH - install the FFmpeg suite to probe video metadata"
+                )
+            } else {
+                anyhow::Error::new(error).context("Failed to execute ffprobe")
+            }
+        })?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "ffprobe exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let probe: FfprobeOutput = serde_json::from_slice(&output.stdout)
+        .context("Failed to parse ffprobe JSON output")?;
+
+    let video_stream = probe
+        .streams
+        .iter()
+        .find(|stream| stream.codec_type == "video")
+        .context("No video stream found in ffprobe output")?;
+
+    let has_audio = probe.streams.iter().any(|stream| stream.codec_type == "audio");
+
+    let fps = video_stream
+        .r_frame_rate
+        .as_deref()
+        .and_then(parse_frame_rate)
+        .unwrap_or(0.0);
+
+    let codec = video_stream.codec_name.as_deref().and_then(parse_codec);
+
+    let duration_secs = probe
+        .format
+        .as_ref()
+        .and_then(|format| format.duration.as_deref())
+        .and_then(|duration| duration.parse::<f64>().ok())
+        .unwrap_or(0.0);
+
+    let bitrate = probe
+        .format
+        .as_ref()
+        .and_then(|format| format.bit_rate.as_deref())
+        .and_then(|bit_rate| bit_rate.parse::<u64>().ok())
+        .map(|bps| (bps / 1000) as u32);
+
+    Ok(VideoMetadata {
+        format,
+        dimensions: ImageDimensions::new(video_stream.width.unwrap_or(0), video_stream.height.unwrap_or(0)),
+        fps,
+        duration: AudioDuration::from_seconds(duration_secs),
+        codec,
+        bitrate,
+        size_bytes,
+        has_audio,
+    })
+}
+
+/// Parses an ffprobe frame rate string like `"30000/1001"` into a decimal FPS.
+#[cfg(feature = "video-probe")]
+fn parse_frame_rate(raw: &str) -> Option<f32> {
+    let (numerator, denominator) = raw.split_once('/')?;
+    let numerator: f32 = numerator.parse().ok()?;
+    let denominator: f32 = denominator.parse().ok()?;
+    if denominator == 0.0 {
+        return None;
+    }
+    Some(numerator / denominator)
+}
+
+/// Maps an ffprobe `codec_name` to our `VideoCodec` enum, if recognized.
+#[cfg(feature = "video-probe")]
+fn parse_codec(raw: &str) -> Option<VideoCodec> {
+    match raw {
+        "h264" => Some(VideoCodec::H264),
+        "hevc" | "h265" => Some(VideoCodec::H265),
+        "vp8" => Some(VideoCodec::Vp8),
+        "vp9" => Some(VideoCodec::Vp9),
+        "av1" => Some(VideoCodec::Av1),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "video-probe")]
+#[derive(Debug, Deserialize)]
+struct FfprobeOutput {
+    format: Option<FfprobeFormat>,
+    streams: Vec<FfprobeStream>,
+}
+
+#[cfg(feature = "video-probe")]
+#[derive(Debug, Deserialize)]
+struct FfprobeFormat {
+    duration: Option<String>,
+    bit_rate: Option<String>,
+}
+
+#[cfg(feature = "video-probe")]
+#[derive(Debug, Deserialize)]
+struct FfprobeStream {
+    codec_type: String,
+    codec_name: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+    r_frame_rate: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,4 +876,232 @@ mod tests {
         assert_eq!(frame.frame_number, 0);
         assert_eq!(frame.timestamp, 0.0);
     }
+
+    #[test]
+    #[cfg(feature = "video-probe")]
+    fn test_parse_frame_rate() {
+        assert_eq!(parse_frame_rate("30/1"), Some(30.0));
+        assert!((parse_frame_rate("30000/1001").unwrap() - 29.97).abs() < 0.01);
+        assert_eq!(parse_frame_rate("30/0"), None);
+        assert_eq!(parse_frame_rate("not-a-rate"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "video-probe")]
+    fn test_parse_codec() {
+        assert_eq!(parse_codec("h264"), Some(VideoCodec::H264));
+        assert_eq!(parse_codec("hevc"), Some(VideoCodec::H265));
+        assert_eq!(parse_codec("vp9"), Some(VideoCodec::Vp9));
+        assert_eq!(parse_codec("mpeg4"), None);
+    }
+
+    #[tokio::test]
+    async fn test_from_path_nonexistent_file_errors() {
+        let result = VideoInput::from_path("/nonexistent/path/video.mp4").await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_frame_sampling_options_default() {
+        let options = FrameSamplingOptions::default();
+        assert!(matches!(options.mode, FrameSamplingMode::FixedFps(fps) if fps == 1.0));
+        assert_eq!(options.max_frames, Some(32));
+        assert_eq!(options.format, ImageFormat::Jpeg);
+        assert_eq!(options.max_dimensions, None);
+    }
+
+    #[test]
+    fn test_scale_filter_none_when_no_cap() {
+        assert_eq!(scale_filter(None), None);
+    }
+
+    #[test]
+    fn test_scale_filter_caps_dimensions() {
+        let filter = scale_filter(Some(ImageDimensions::new(800, 600))).unwrap();
+        assert!(filter.contains("min(800,iw)"));
+        assert!(filter.contains("min(600,ih)"));
+    }
+
+    #[test]
+    fn test_frame_extension() {
+        assert_eq!(frame_extension(ImageFormat::Jpeg), "jpg");
+        assert_eq!(frame_extension(ImageFormat::Png), "png");
+    }
+
+    #[tokio::test]
+    async fn test_sample_frames_rejects_base64_source() {
+        let input = VideoInput::Base64 {
+            data: "fake".to_string(),
+            media_type: "video/mp4".to_string(),
+            metadata: None,
+        };
+
+        let result = input.sample_frames(FrameSamplingOptions::default()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sample_frames_fixed_count_requires_duration_metadata() {
+        let input = VideoInput::Path {
+            path: PathBuf::from("/tmp/video.mp4"),
+            metadata: None,
+        };
+
+        let options = FrameSamplingOptions {
+            mode: FrameSamplingMode::FixedCount(5),
+            ..FrameSamplingOptions::default()
+        };
+
+        let result = input.sample_frames(options).await;
+        assert!(result.is_err());
+    }
+
+    const MASTER_PLAYLIST: &str = "#EXTM3U\n\
+#EXT-X-STREAM-INF:BANDWIDTH=800000,RESOLUTION=640x360,FRAME-RATE=30.000,CODECS=\"avc1.42001e,mp4a.40.2\"\n\
+low/index.m3u8\n\
+#EXT-X-STREAM-INF:BANDWIDTH=2800000,RESOLUTION=1280x720,FRAME-RATE=30.000,CODECS=\"avc1.4d4020,mp4a.40.2\"\n\
+mid/index.m3u8\n\
+#EXT-X-STREAM-INF:BANDWIDTH=5000000,RESOLUTION=1920x1080,FRAME-RATE=59.940,CODECS=\"avc1.640028\"\n\
+https://cdn.example.com/high/index.m3u8\n";
+
+    #[test]
+    fn test_parse_hls_variants() {
+        let variants = parse_hls_variants(MASTER_PLAYLIST);
+        assert_eq!(variants.len(), 3);
+        assert_eq!(variants[0].bandwidth, 800000);
+        assert_eq!(variants[0].resolution, Some((640, 360)));
+        assert_eq!(variants[0].frame_rate, Some(30.0));
+        assert_eq!(variants[0].codecs.as_deref(), Some("avc1.42001e,mp4a.40.2"));
+        assert_eq!(variants[2].uri, "https://cdn.example.com/high/index.m3u8");
+    }
+
+    #[test]
+    fn test_variant_selector_max_bandwidth_and_resolution() {
+        let variants = parse_hls_variants(MASTER_PLAYLIST);
+        assert_eq!(VariantSelector::MaxBandwidth.select_variant(&variants).unwrap().uri, "https://cdn.example.com/high/index.m3u8");
+        assert_eq!(VariantSelector::MaxResolution.select_variant(&variants).unwrap().uri, "https://cdn.example.com/high/index.m3u8");
+    }
+
+    #[test]
+    fn test_variant_selector_closest_to() {
+        let variants = parse_hls_variants(MASTER_PLAYLIST);
+        let selected = VariantSelector::ClosestTo { width: 1300, height: 700 }.select_variant(&variants).unwrap();
+        assert_eq!(selected.uri, "mid/index.m3u8");
+    }
+
+    #[test]
+    fn test_variant_selector_exact_bandwidth() {
+        let variants = parse_hls_variants(MASTER_PLAYLIST);
+        let selected = VariantSelector::ExactBandwidth(2_900_000).select_variant(&variants).unwrap();
+        assert_eq!(selected.uri, "mid/index.m3u8");
+    }
+
+    #[test]
+    fn test_variant_metadata_derives_dimensions_fps_and_codec() {
+        let variants = parse_hls_variants(MASTER_PLAYLIST);
+        let metadata = variant_metadata(&variants[1]);
+        assert_eq!(metadata.dimensions, ImageDimensions::new(1280, 720));
+        assert_eq!(metadata.fps, 30.0);
+        assert_eq!(metadata.codec, Some(VideoCodec::H264));
+        assert!(metadata.has_audio);
+        assert_eq!(metadata.bitrate, Some(2800));
+    }
+
+    #[test]
+    fn test_resolve_hls_uri() {
+        assert_eq!(
+            resolve_hls_uri("https://cdn.example.com/streams/master.m3u8", "low/index.m3u8"),
+            "https://cdn.example.com/streams/low/index.m3u8"
+        );
+        assert_eq!(
+            resolve_hls_uri("https://cdn.example.com/streams/master.m3u8", "https://other.example.com/variant.m3u8"),
+            "https://other.example.com/variant.m3u8"
+        );
+    }
+
+    #[test]
+    fn test_variant_selector_default() {
+        assert_eq!(VariantSelector::default(), VariantSelector::MaxBandwidth);
+    }
+
+    #[test]
+    fn test_from_hls() {
+        let input = VideoInput::from_hls("https://cdn.example.com/master.m3u8", VariantSelector::MaxResolution);
+        match input {
+            VideoInput::Hls { master_url, selector, .. } => {
+                assert_eq!(master_url, "https://cdn.example.com/master.m3u8");
+                assert_eq!(selector, VariantSelector::MaxResolution);
+            }
+            _ => panic!("expected Hls variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_hls_variant_rejects_non_hls_input() {
+        let input = VideoInput::from_url("https://cdn.example.com/video.mp4");
+        let result = input.resolve_hls_variant().await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sample_frames_passthrough_for_existing_frames() {
+        let frame = VideoFrame::new(0, 0.0, "data".to_string(), ImageFormat::Jpeg);
+        let input = VideoInput::from_frames(vec![frame], 1.0);
+
+        let result = input.sample_frames(FrameSamplingOptions::default()).await.unwrap();
+        match result {
+            VideoInput::Frames { frames, .. } => assert_eq!(frames.len(), 1),
+            _ => panic!("expected Frames variant"),
+        }
+    }
+
+    fn sample_metadata() -> VideoMetadata {
+        VideoMetadata {
+            format: VideoFormat::Mp4,
+            dimensions: ImageDimensions::new(3840, 2160),
+            fps: 30.0,
+            duration: AudioDuration::new(10, 0),
+            codec: Some(VideoCodec::Av1),
+            bitrate: None,
+            size_bytes: 10,
+            has_audio: true,
+        }
+    }
+
+    #[test]
+    fn test_validate_against_passes_with_no_limits() {
+        let input = VideoInput::Path { path: PathBuf::from("clip.mp4"), metadata: Some(sample_metadata()) };
+        assert!(input.validate_against(&MediaLimits::default()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_against_reports_all_violations() {
+        let input = VideoInput::Path { path: PathBuf::from("clip.mp4"), metadata: Some(sample_metadata()) };
+        let limits = MediaLimits {
+            max_width: Some(1920),
+            max_height: Some(1080),
+            allowed_formats: vec!["WebM".to_string()],
+            allowed_codecs: vec!["H264".to_string()],
+            ..Default::default()
+        };
+
+        let violations = input.validate_against(&limits).unwrap_err();
+        assert!(violations.iter().any(|v| matches!(v, MediaLimitViolation::WidthExceeded { .. })));
+        assert!(violations.iter().any(|v| matches!(v, MediaLimitViolation::HeightExceeded { .. })));
+        assert!(violations.iter().any(|v| matches!(v, MediaLimitViolation::DisallowedFormat { .. })));
+        assert!(violations.iter().any(|v| matches!(v, MediaLimitViolation::DisallowedCodec { .. })));
+    }
+
+    #[test]
+    fn test_validate_against_rejects_oversized_base64() {
+        let input = VideoInput::Base64 {
+            data: "aGVsbG8h".to_string(), // decodes to 6 bytes
+            media_type: "video/mp4".to_string(),
+            metadata: None,
+        };
+        let limits = MediaLimits { max_file_size_bytes: Some(5), ..Default::default() };
+
+        let violations = input.validate_against(&limits).unwrap_err();
+        assert!(matches!(violations[0], MediaLimitViolation::FileTooLarge { actual: 6, limit: 5 }));
+    }
 }
@@ -266,6 +266,7 @@ impl ModelRouter {
                     return false;
                 }
             }
+
         } else {
             // No profile available - be permissive and allow it
             // In production, might want to be more conservative
@@ -5,31 +5,55 @@
 // except according to those terms.
 
 //! AWS Bedrock provider implementation
+//!
+//! Talks to the Bedrock Runtime `InvokeModel` and
+//! `InvokeModelWithResponseStream` REST endpoints directly over `reqwest`,
+//! signing every request with AWS Signature Version 4. This keeps the crate
+//! free of the AWS SDK (and its large dependency tree) at the cost of
+//! implementing the signing process and per-model request/response schemas
+//! ourselves.
+//!
+//! Each Bedrock model family speaks a different JSON dialect, so requests
+//! and responses are translated through [`ModelFamily`] rather than a single
+//! shared schema.
 
 use super::{CompletionRequest, CompletionResponse, FinishReason, ModelInfo, Provider, ProviderError, ResponseStream, TokenUsage};
 use async_trait::async_trait;
-use futures::stream;
-use serde::{Deserialize, Serialize};
+use futures::StreamExt;
+use hmac::Hmac;
+use serde::Deserialize;
+use sha2::Sha256;
 use std::time::Duration;
-use tracing::{debug, error, warn};
+use tracing::{debug, trace, warn};
+
+const SERVICE: &str = "bedrock";
+const DEFAULT_TIMEOUT_SECS: u64 = 120;
+const MAX_RETRIES: u32 = 3;
+const BASE_RETRY_DELAY_MS: u64 = 1000;
 
 /// AWS Bedrock provider
-/// Note: This is a simplified implementation. Full AWS Bedrock support requires AWS SDK.
+///
+/// Implements the [`Provider`] trait against Bedrock Runtime's HTTP API,
+/// signing requests with SigV4 built on `hmac`/`sha2` rather than pulling in
+/// the AWS SDK.
 pub struct BedrockProvider {
     client: reqwest::Client,
     region: String,
     access_key: String,
     secret_key: String,
+    session_token: Option<String>,
+    max_retries: u32,
 }
 
 impl BedrockProvider {
+    /// Create a new Bedrock provider for the given region and long-lived IAM credentials
     pub fn new(region: String, access_key: String, secret_key: String) -> Result<Self, ProviderError> {
         if access_key.is_empty() || secret_key.is_empty() {
             return Err(ProviderError::InvalidApiKey);
         }
 
         let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(120))
+            .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
             .use_rustls_tls()
             .build()
             .map_err(|e| ProviderError::InternalError(format!("Failed to build HTTP client: {}", e)))?;
@@ -39,26 +63,204 @@ impl BedrockProvider {
             region,
             access_key,
             secret_key,
+            session_token: None,
+            max_retries: MAX_RETRIES,
         })
     }
+
+    /// Attach a temporary session token, for credentials minted by an STS `AssumeRole` call
+    pub fn with_session_token(mut self, session_token: impl Into<String>) -> Self {
+        self.session_token = Some(session_token.into());
+        self
+    }
+
+    /// The Bedrock Runtime host for this provider's region (e.g. `bedrock-runtime.us-east-1.amazonaws.com`)
+    fn host(&self) -> String {
+        format!("bedrock-runtime.{}.amazonaws.com", self.region)
+    }
+
+    fn invoke_path(model_id: &str, streaming: bool) -> String {
+        if streaming {
+            format!("/model/{}/invoke-with-response-stream", model_id)
+        } else {
+            format!("/model/{}/invoke", model_id)
+        }
+    }
+
+    /// Sign and send a single `InvokeModel`-family request
+    async fn send_signed(&self, model_id: &str, streaming: bool, body: &[u8]) -> Result<reqwest::Response, ProviderError> {
+        let host = self.host();
+        let path = Self::invoke_path(model_id, streaming);
+        let url = format!("https://{}{}", host, path);
+
+        let signer = sigv4::SigV4Signer {
+            access_key: &self.access_key,
+            secret_key: &self.secret_key,
+            session_token: self.session_token.as_deref(),
+            region: &self.region,
+            service: SERVICE,
+        };
+        let headers = signer.sign("POST", &path, &host, body);
+
+        let mut req = self.client.post(&url).header("content-type", "application/json").body(body.to_vec());
+        for (name, value) in headers {
+            req = req.header(name, value);
+        }
+
+        req.send().await.map_err(ProviderError::NetworkError)
+    }
+
+    /// Check if an error is retryable
+    fn is_retryable(error: &ProviderError) -> bool {
+        match error {
+            ProviderError::NetworkError(_) => true,
+            ProviderError::RateLimitExceeded { .. } => true,
+            ProviderError::ApiError { status, .. } if *status >= 500 || *status == 429 => true,
+            ProviderError::Timeout(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Calculate exponential backoff delay
+    fn calculate_backoff(attempt: u32) -> Duration {
+        const MAX_DELAY_MS: u64 = 60_000;
+        let delay_ms = BASE_RETRY_DELAY_MS * 2_u64.pow(attempt);
+        Duration::from_millis(delay_ms.min(MAX_DELAY_MS))
+    }
+
+    /// Parse a Bedrock error response body
+    fn parse_error_response(status: u16, text: &str) -> ProviderError {
+        #[derive(Deserialize)]
+        struct BedrockError {
+            #[serde(rename = "Message", alias = "message")]
+            message: Option<String>,
+        }
+
+        let message = serde_json::from_str::<BedrockError>(text)
+            .ok()
+            .and_then(|e| e.message)
+            .unwrap_or_else(|| text.to_string());
+
+        match status {
+            401 | 403 => ProviderError::AuthenticationError(message),
+            429 => ProviderError::RateLimitExceeded { retry_after: None },
+            400 if message.to_lowercase().contains("too long") => {
+                ProviderError::ContextLengthExceeded { tokens: 0, max: 0 }
+            }
+            _ => ProviderError::ApiError { status, message },
+        }
+    }
+
+    /// Parse a mid-stream exception frame (`:message-type: exception`) from
+    /// `InvokeModelWithResponseStream`. Unlike `parse_error_response`, these
+    /// arrive over an already-200-OK stream, so the exception type - not an
+    /// HTTP status - is what tells `ThrottlingException` apart from a fatal
+    /// `ModelStreamErrorException` or `ValidationException`.
+    fn parse_stream_exception(frame: &eventstream::Frame) -> ProviderError {
+        #[derive(Deserialize)]
+        struct BedrockStreamException {
+            #[serde(rename = "message", alias = "Message")]
+            message: Option<String>,
+        }
+
+        let message = serde_json::from_slice::<BedrockStreamException>(&frame.payload)
+            .ok()
+            .and_then(|e| e.message)
+            .unwrap_or_else(|| String::from_utf8_lossy(&frame.payload).into_owned());
+        let exception_type = frame.exception_type().unwrap_or("UnknownException");
+
+        match exception_type {
+            "ThrottlingException" => ProviderError::RateLimitExceeded { retry_after: None },
+            _ => ProviderError::ApiError {
+                status: 500,
+                message: format!("{}: {}", exception_type, message),
+            },
+        }
+    }
+
+    async fn complete_once(&self, request: &CompletionRequest) -> Result<CompletionResponse, ProviderError> {
+        let family = ModelFamily::for_model(&request.model);
+        let body = family.build_request(request);
+        let body_bytes = serde_json::to_vec(&body)?;
+
+        debug!("Bedrock InvokeModel request: model={}, family={:?}", request.model, family);
+
+        let response = self.send_signed(&request.model, false, &body_bytes).await?;
+        let status = response.status();
+        let text = response.text().await.map_err(ProviderError::NetworkError)?;
+
+        if !status.is_success() {
+            warn!("Bedrock API error: status={}, response={}", status, text);
+            return Err(Self::parse_error_response(status.as_u16(), &text));
+        }
+
+        trace!("Bedrock response: {}", text);
+        let json: serde_json::Value = serde_json::from_str(&text)?;
+        family.parse_response(&request.model, &json)
+    }
 }
 
 #[async_trait]
 impl Provider for BedrockProvider {
     async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, ProviderError> {
-        // Note: Full Bedrock implementation requires AWS SigV4 signing
-        // This is a placeholder that shows the structure
-        warn!("AWS Bedrock provider requires AWS SDK for full implementation");
+        let mut attempts = 0;
+        let mut last_error = None;
+
+        while attempts <= self.max_retries {
+            match self.complete_once(&request).await {
+                Ok(response) => return Ok(response),
+                Err(e) if Self::is_retryable(&e) && attempts < self.max_retries => {
+                    warn!("Retryable Bedrock error: {}. Retrying...", e);
+                    tokio::time::sleep(Self::calculate_backoff(attempts)).await;
+                    last_error = Some(e);
+                    attempts += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
 
-        Err(ProviderError::InternalError(
-            "AWS Bedrock provider requires AWS SDK. Please use AWS SDK directly or use a wrapper service.".to_string()
-        ))
+        Err(last_error.unwrap_or_else(|| ProviderError::InternalError("Retry loop exhausted".to_string())))
     }
 
-    async fn stream(&self, _request: CompletionRequest) -> Result<ResponseStream, ProviderError> {
-        Err(ProviderError::InternalError(
-            "AWS Bedrock streaming requires AWS SDK implementation".to_string()
-        ))
+    async fn stream(&self, request: CompletionRequest) -> Result<ResponseStream, ProviderError> {
+        let family = ModelFamily::for_model(&request.model);
+        let body = family.build_request(&request);
+        let body_bytes = serde_json::to_vec(&body)?;
+
+        debug!("Bedrock InvokeModelWithResponseStream request: model={}, family={:?}", request.model, family);
+
+        let response = self.send_signed(&request.model, true, &body_bytes).await?;
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(Self::parse_error_response(status.as_u16(), &text));
+        }
+
+        let model_id = request.model.clone();
+        let mut buffer: Vec<u8> = Vec::new();
+
+        let stream = response.bytes_stream().flat_map(move |chunk| {
+            let mut items = Vec::new();
+
+            match chunk {
+                Ok(bytes) => {
+                    buffer.extend_from_slice(&bytes);
+                    while let Some((frame_len, frame)) = eventstream::next_frame(&buffer) {
+                        buffer.drain(..frame_len);
+                        if frame.is_exception() {
+                            items.push(Err(Self::parse_stream_exception(&frame)));
+                        } else if let Some(text) = decode_stream_payload(&model_id, &frame.payload) {
+                            items.push(Ok(text));
+                        }
+                    }
+                }
+                Err(e) => items.push(Err(ProviderError::NetworkError(e))),
+            }
+
+            futures::stream::iter(items)
+        });
+
+        Ok(Box::pin(stream))
     }
 
     fn supported_models(&self) -> Vec<ModelInfo> {
@@ -69,7 +271,7 @@ impl Provider for BedrockProvider {
             ModelInfo::new("amazon.titan-text-express-v1", "Titan Text Express", 8000, true, false),
             ModelInfo::new("meta.llama2-70b-chat-v1", "Llama 2 70B", 4096, true, false),
             ModelInfo::new("cohere.command-text-v14", "Command", 4096, true, false),
-            ModelInfo::new("ai21.j2-ultra-v1", "Jurassic-2 Ultra", 8191, true, false),
+            ModelInfo::new("ai21.j2-ultra-v1", "Jurassic-2 Ultra", 8191, false, false),
         ]
     }
 
@@ -102,3 +304,633 @@ impl Provider for BedrockProvider {
         Ok((text.len() / 4).max(1))
     }
 }
+
+/// The JSON dialect a Bedrock model family speaks for `InvokeModel`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModelFamily {
+    Anthropic,
+    Titan,
+    Llama,
+    Cohere,
+    Ai21,
+}
+
+impl ModelFamily {
+    fn for_model(model_id: &str) -> Self {
+        if model_id.starts_with("anthropic.") {
+            ModelFamily::Anthropic
+        } else if model_id.starts_with("amazon.titan") {
+            ModelFamily::Titan
+        } else if model_id.starts_with("meta.llama") {
+            ModelFamily::Llama
+        } else if model_id.starts_with("cohere.") {
+            ModelFamily::Cohere
+        } else if model_id.starts_with("ai21.") {
+            ModelFamily::Ai21
+        } else {
+            // Default to the Anthropic Messages dialect, the most common case
+            ModelFamily::Anthropic
+        }
+    }
+
+    fn build_request(&self, request: &CompletionRequest) -> serde_json::Value {
+        let max_tokens = request.max_tokens.unwrap_or(1024);
+
+        match self {
+            ModelFamily::Anthropic => serde_json::json!({
+                "anthropic_version": "bedrock-2023-05-31",
+                "messages": [{"role": "user", "content": request.prompt}],
+                "max_tokens": max_tokens,
+                "temperature": request.temperature,
+                "top_p": request.top_p,
+                "stop_sequences": request.stop,
+            }),
+            ModelFamily::Titan => serde_json::json!({
+                "inputText": request.prompt,
+                "textGenerationConfig": {
+                    "maxTokenCount": max_tokens,
+                    "temperature": request.temperature,
+                    "topP": request.top_p,
+                    "stopSequences": request.stop.clone().unwrap_or_default(),
+                },
+            }),
+            ModelFamily::Llama => serde_json::json!({
+                "prompt": request.prompt,
+                "max_gen_len": max_tokens,
+                "temperature": request.temperature,
+                "top_p": request.top_p,
+            }),
+            ModelFamily::Cohere => serde_json::json!({
+                "prompt": request.prompt,
+                "max_tokens": max_tokens,
+                "temperature": request.temperature,
+                "p": request.top_p,
+                "stop_sequences": request.stop,
+            }),
+            ModelFamily::Ai21 => serde_json::json!({
+                "prompt": request.prompt,
+                "maxTokens": max_tokens,
+                "temperature": request.temperature,
+                "topP": request.top_p,
+                "stopSequences": request.stop.clone().unwrap_or_default(),
+            }),
+        }
+    }
+
+    fn parse_response(&self, model_id: &str, json: &serde_json::Value) -> Result<CompletionResponse, ProviderError> {
+        let bad_response = |msg: &str| ProviderError::ApiError { status: 500, message: msg.to_string() };
+
+        let (content, finish_reason, prompt_tokens, completion_tokens) = match self {
+            ModelFamily::Anthropic => {
+                let content = json["content"][0]["text"]
+                    .as_str()
+                    .ok_or_else(|| bad_response("Missing content in Anthropic response"))?
+                    .to_string();
+                let finish_reason = match json["stop_reason"].as_str().unwrap_or("") {
+                    "end_turn" | "stop_sequence" => FinishReason::Stop,
+                    "max_tokens" => FinishReason::Length,
+                    _ => FinishReason::Stop,
+                };
+                let prompt_tokens = json["usage"]["input_tokens"].as_u64().unwrap_or(0) as usize;
+                let completion_tokens = json["usage"]["output_tokens"].as_u64().unwrap_or(0) as usize;
+                (content, finish_reason, prompt_tokens, completion_tokens)
+            }
+            ModelFamily::Titan => {
+                let result = &json["results"][0];
+                let content = result["outputText"].as_str().unwrap_or_default().to_string();
+                let finish_reason = match result["completionReason"].as_str().unwrap_or("") {
+                    "LENGTH" => FinishReason::Length,
+                    "CONTENT_FILTERED" => FinishReason::ContentFilter,
+                    _ => FinishReason::Stop,
+                };
+                let prompt_tokens = json["inputTextTokenCount"].as_u64().unwrap_or(0) as usize;
+                let completion_tokens = result["tokenCount"].as_u64().unwrap_or(0) as usize;
+                (content, finish_reason, prompt_tokens, completion_tokens)
+            }
+            ModelFamily::Llama => {
+                let content = json["generation"].as_str().unwrap_or_default().to_string();
+                let finish_reason = match json["stop_reason"].as_str().unwrap_or("") {
+                    "length" => FinishReason::Length,
+                    _ => FinishReason::Stop,
+                };
+                let prompt_tokens = json["prompt_token_count"].as_u64().unwrap_or(0) as usize;
+                let completion_tokens = json["generation_token_count"].as_u64().unwrap_or(0) as usize;
+                (content, finish_reason, prompt_tokens, completion_tokens)
+            }
+            ModelFamily::Cohere => {
+                let generation = &json["generations"][0];
+                let content = generation["text"].as_str().unwrap_or_default().to_string();
+                let finish_reason = match generation["finish_reason"].as_str().unwrap_or("") {
+                    "MAX_TOKENS" => FinishReason::Length,
+                    _ => FinishReason::Stop,
+                };
+                // Cohere on Bedrock doesn't report token counts, so estimate from text length
+                (content.clone(), finish_reason, 0, (content.len() / 4).max(1))
+            }
+            ModelFamily::Ai21 => {
+                let completion = &json["completions"][0];
+                let content = completion["data"]["text"].as_str().unwrap_or_default().to_string();
+                let finish_reason = match completion["finishReason"]["reason"].as_str().unwrap_or("") {
+                    "length" => FinishReason::Length,
+                    _ => FinishReason::Stop,
+                };
+                (content.clone(), finish_reason, 0, (content.len() / 4).max(1))
+            }
+        };
+
+        Ok(CompletionResponse {
+            id: format!("bedrock-{}", chrono::Utc::now().timestamp_millis()),
+            model: model_id.to_string(),
+            content,
+            usage: TokenUsage {
+                prompt_tokens,
+                completion_tokens,
+                total_tokens: prompt_tokens + completion_tokens,
+            },
+            finish_reason,
+            created_at: chrono::Utc::now(),
+        })
+    }
+
+    /// Extract the incremental text from one decoded streaming chunk, if any
+    fn extract_stream_text(&self, chunk: &serde_json::Value) -> Option<String> {
+        match self {
+            ModelFamily::Anthropic => {
+                if chunk["type"].as_str() == Some("content_block_delta") {
+                    chunk["delta"]["text"].as_str().map(|s| s.to_string())
+                } else {
+                    None
+                }
+            }
+            ModelFamily::Titan => chunk["outputText"].as_str().map(|s| s.to_string()),
+            ModelFamily::Llama => chunk["generation"].as_str().map(|s| s.to_string()),
+            ModelFamily::Cohere => chunk["text"].as_str().map(|s| s.to_string()),
+            ModelFamily::Ai21 => chunk["data"]["text"].as_str().map(|s| s.to_string()),
+        }
+        .filter(|s| !s.is_empty())
+    }
+}
+
+/// Unwrap one Bedrock event-stream payload (`{"bytes": "<base64>", ...}`)
+/// and extract the incremental text for the model's streaming dialect
+fn decode_stream_payload(model_id: &str, payload: &[u8]) -> Option<String> {
+    let outer: serde_json::Value = serde_json::from_slice(payload).ok()?;
+    let encoded = outer.get("bytes")?.as_str()?;
+
+    use base64::{engine::general_purpose, Engine as _};
+    let decoded = general_purpose::STANDARD.decode(encoded).ok()?;
+    let inner: serde_json::Value = serde_json::from_slice(&decoded).ok()?;
+
+    ModelFamily::for_model(model_id).extract_stream_text(&inner)
+}
+
+/// Minimal decoder for the `application/vnd.amazon.eventstream` binary
+/// framing Bedrock uses for `InvokeModelWithResponseStream`.
+mod eventstream {
+    use std::collections::HashMap;
+
+    const PRELUDE_LEN: usize = 8;
+    const TRAILING_CRC_LEN: usize = 4;
+
+    /// String-valued header, AWS's encoding for every header Bedrock sends
+    /// on this stream (`:message-type`, `:content-type`, `:exception-type`, ...)
+    const HEADER_VALUE_TYPE_STRING: u8 = 7;
+
+    /// One decoded event-stream message: its headers (`:message-type` and
+    /// friends) plus the payload bytes that follow them.
+    pub struct Frame {
+        pub headers: HashMap<String, String>,
+        pub payload: Vec<u8>,
+    }
+
+    impl Frame {
+        /// `true` for Bedrock's mid-stream exception frames (throttling,
+        /// `ModelStreamErrorException`, etc.), which arrive as a regular
+        /// event-stream message rather than an HTTP error status.
+        pub fn is_exception(&self) -> bool {
+            self.headers.get(":message-type").map(String::as_str) == Some("exception")
+        }
+
+        pub fn exception_type(&self) -> Option<&str> {
+            self.headers.get(":exception-type").map(String::as_str)
+        }
+    }
+
+    /// Extract the next complete frame from `buf`, if one is fully
+    /// buffered. Returns `(total_frame_length, frame)` so the caller can
+    /// drain `total_frame_length` bytes off the front of `buf`.
+    ///
+    /// CRC checksums are not verified: the connection is already
+    /// TLS-protected, and losing a chunk just means a dropped partial
+    /// response rather than a security concern.
+    pub fn next_frame(buf: &[u8]) -> Option<(usize, Frame)> {
+        if buf.len() < PRELUDE_LEN + TRAILING_CRC_LEN {
+            return None;
+        }
+
+        let total_length = u32::from_be_bytes(buf[0..4].try_into().ok()?) as usize;
+        let headers_length = u32::from_be_bytes(buf[4..8].try_into().ok()?) as usize;
+
+        if buf.len() < total_length {
+            return None;
+        }
+
+        let headers_start = PRELUDE_LEN + 4; // prelude + prelude CRC
+        let payload_start = headers_start + headers_length;
+        let payload_end = total_length.saturating_sub(TRAILING_CRC_LEN);
+
+        if payload_end < payload_start || payload_end > buf.len() {
+            return None;
+        }
+
+        let headers = parse_headers(&buf[headers_start..payload_start]);
+        let payload = buf[payload_start..payload_end].to_vec();
+
+        Some((total_length, Frame { headers, payload }))
+    }
+
+    /// Parse the event-stream header block: a sequence of
+    /// `name_len(1) name(utf8) value_type(1) value` entries. Parsing stops
+    /// at the first non-string header value rather than erroring, returning
+    /// the headers read so far, since Bedrock's
+    /// `:message-type`/`:exception-type` headers are always strings and
+    /// the others aren't needed here.
+    fn parse_headers(mut buf: &[u8]) -> HashMap<String, String> {
+        let mut headers = HashMap::new();
+
+        while !buf.is_empty() {
+            let Some((&name_len, rest)) = buf.split_first() else { break };
+            let name_len = name_len as usize;
+            if rest.len() < name_len + 1 {
+                break;
+            }
+
+            let name = String::from_utf8_lossy(&rest[..name_len]).into_owned();
+            let value_type = rest[name_len];
+            let rest = &rest[name_len + 1..];
+
+            if value_type != HEADER_VALUE_TYPE_STRING {
+                // Unknown length for a non-string value - stop rather than
+                // misparse the remaining headers.
+                break;
+            }
+            if rest.len() < 2 {
+                break;
+            }
+            let value_len = u16::from_be_bytes(rest[0..2].try_into().unwrap()) as usize;
+            if rest.len() < 2 + value_len {
+                break;
+            }
+            let value = String::from_utf8_lossy(&rest[2..2 + value_len]).into_owned();
+
+            headers.insert(name, value);
+            buf = &rest[2 + value_len..];
+        }
+
+        headers
+    }
+}
+
+/// AWS Signature Version 4 request signing, built directly on `hmac`/`sha2`
+/// so the provider doesn't depend on the AWS SDK's signing crates.
+mod sigv4 {
+    use super::{Hmac, Sha256};
+    use chrono::Utc;
+    use hmac::Mac;
+    use sha2::Digest;
+
+    type HmacSha256 = Hmac<Sha256>;
+
+    pub struct SigV4Signer<'a> {
+        pub access_key: &'a str,
+        pub secret_key: &'a str,
+        pub session_token: Option<&'a str>,
+        pub region: &'a str,
+        pub service: &'a str,
+    }
+
+    impl<'a> SigV4Signer<'a> {
+        /// Sign a request, returning the headers to attach (`x-amz-date`,
+        /// `x-amz-content-sha256`, `authorization`, and `x-amz-security-token`
+        /// when a session token is set).
+        pub fn sign(&self, method: &str, path: &str, host: &str, body: &[u8]) -> Vec<(String, String)> {
+            let now = Utc::now();
+            let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+            let date_stamp = now.format("%Y%m%d").to_string();
+            let payload_hash = sha256_hex(body);
+
+            let mut headers = vec![
+                ("host".to_string(), host.to_string()),
+                ("x-amz-content-sha256".to_string(), payload_hash.clone()),
+                ("x-amz-date".to_string(), amz_date.clone()),
+            ];
+            if let Some(token) = self.session_token {
+                headers.push(("x-amz-security-token".to_string(), token.to_string()));
+            }
+            headers.sort_by(|a, b| a.0.cmp(&b.0));
+
+            let signed_headers = headers.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>().join(";");
+            let canonical_headers: String = headers.iter().map(|(k, v)| format!("{}:{}\n", k, v.trim())).collect();
+
+            let canonical_request = format!(
+                "{}\n{}\n{}\n{}\n{}\n{}",
+                method,
+                uri_encode_path(path),
+                "", // no query string
+                canonical_headers,
+                signed_headers,
+                payload_hash,
+            );
+
+            let credential_scope = format!("{}/{}/{}/aws4_request", date_stamp, self.region, self.service);
+            let string_to_sign = format!(
+                "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+                amz_date,
+                credential_scope,
+                sha256_hex(canonical_request.as_bytes()),
+            );
+
+            let signing_key = derive_signing_key(self.secret_key, &date_stamp, self.region, self.service);
+            let signature = to_hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+            let authorization = format!(
+                "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                self.access_key, credential_scope, signed_headers, signature,
+            );
+
+            let mut result = vec![
+                ("x-amz-date".to_string(), amz_date),
+                ("x-amz-content-sha256".to_string(), payload_hash),
+                ("authorization".to_string(), authorization),
+            ];
+            if let Some(token) = self.session_token {
+                result.push(("x-amz-security-token".to_string(), token.to_string()));
+            }
+            result
+        }
+    }
+
+    /// Derive the SigV4 signing key via the HMAC-SHA256 chain:
+    /// date -> region -> service -> `aws4_request`
+    fn derive_signing_key(secret_key: &str, date_stamp: &str, region: &str, service: &str) -> Vec<u8> {
+        let k_date = hmac_sha256(format!("AWS4{}", secret_key).as_bytes(), date_stamp.as_bytes());
+        let k_region = hmac_sha256(&k_date, region.as_bytes());
+        let k_service = hmac_sha256(&k_region, service.as_bytes());
+        hmac_sha256(&k_service, b"aws4_request")
+    }
+
+    fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut mac = HmacSha256::new_from_slice(key).expect("HMAC-SHA256 accepts keys of any length");
+        mac.update(data);
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    fn sha256_hex(data: &[u8]) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(data);
+        to_hex(&hasher.finalize())
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// URI-encode a path for the canonical request, preserving `/` separators
+    fn uri_encode_path(path: &str) -> String {
+        path.split('/')
+            .map(uri_encode_segment)
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn uri_encode_segment(segment: &str) -> String {
+        segment
+            .bytes()
+            .map(|b| {
+                let c = b as char;
+                if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '~') {
+                    c.to_string()
+                } else {
+                    format!("%{:02X}", b)
+                }
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_derive_signing_key_matches_aws_published_vector() {
+            // From AWS's "Examples of the Complete Version 4 Signing Process"
+            // documentation: secret key wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY,
+            // date 20150830, region us-east-1, service iam.
+            let key = derive_signing_key("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY", "20150830", "us-east-1", "iam");
+            assert_eq!(to_hex(&key), "c4afb1cc5771d871763a393e44b703571b55cc28424d1a5e86da6ed3c154a4b9");
+        }
+
+        #[test]
+        fn test_sha256_hex_of_empty_string() {
+            assert_eq!(sha256_hex(b""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        }
+
+        #[test]
+        fn test_uri_encode_path_preserves_slashes_and_encodes_colons() {
+            assert_eq!(
+                uri_encode_path("/model/anthropic.claude-3-sonnet-20240229-v1:0/invoke"),
+                "/model/anthropic.claude-3-sonnet-20240229-v1%3A0/invoke"
+            );
+        }
+
+        #[test]
+        fn test_sign_produces_expected_headers() {
+            let signer = SigV4Signer {
+                access_key: "AKIDEXAMPLE",
+                secret_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+                session_token: None,
+                region: "us-east-1",
+                service: "bedrock",
+            };
+
+            let headers = signer.sign("POST", "/model/amazon.titan-text-express-v1/invoke", "bedrock-runtime.us-east-1.amazonaws.com", b"{}");
+
+            let names: Vec<_> = headers.iter().map(|(k, _)| k.as_str()).collect();
+            assert!(names.contains(&"authorization"));
+            assert!(names.contains(&"x-amz-date"));
+            assert!(names.contains(&"x-amz-content-sha256"));
+
+            let auth = headers.iter().find(|(k, _)| k == "authorization").unwrap();
+            assert!(auth.1.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/"));
+            assert!(auth.1.contains("SignedHeaders="));
+            assert!(auth.1.contains("Signature="));
+        }
+
+        #[test]
+        fn test_sign_includes_security_token_when_present() {
+            let signer = SigV4Signer {
+                access_key: "AKIDEXAMPLE",
+                secret_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+                session_token: Some("session-token-value"),
+                region: "us-east-1",
+                service: "bedrock",
+            };
+
+            let headers = signer.sign("POST", "/model/amazon.titan-text-express-v1/invoke", "bedrock-runtime.us-east-1.amazonaws.com", b"{}");
+
+            let token_header = headers.iter().find(|(k, _)| k == "x-amz-security-token");
+            assert_eq!(token_header.map(|(_, v)| v.as_str()), Some("session-token-value"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bedrock_provider_creation() {
+        let provider = BedrockProvider::new("us-east-1".to_string(), "AKIDEXAMPLE".to_string(), "secret".to_string()).unwrap();
+        assert_eq!(provider.name(), "bedrock");
+        assert_eq!(provider.host(), "bedrock-runtime.us-east-1.amazonaws.com");
+    }
+
+    #[test]
+    fn test_empty_credentials_rejected() {
+        let result = BedrockProvider::new("us-east-1".to_string(), "".to_string(), "".to_string());
+        assert!(matches!(result, Err(ProviderError::InvalidApiKey)));
+    }
+
+    #[test]
+    fn test_model_family_detection() {
+        assert_eq!(ModelFamily::for_model("anthropic.claude-3-sonnet-20240229-v1:0"), ModelFamily::Anthropic);
+        assert_eq!(ModelFamily::for_model("amazon.titan-text-express-v1"), ModelFamily::Titan);
+        assert_eq!(ModelFamily::for_model("meta.llama2-70b-chat-v1"), ModelFamily::Llama);
+        assert_eq!(ModelFamily::for_model("cohere.command-text-v14"), ModelFamily::Cohere);
+        assert_eq!(ModelFamily::for_model("ai21.j2-ultra-v1"), ModelFamily::Ai21);
+    }
+
+    #[test]
+    fn test_build_request_anthropic() {
+        let request = CompletionRequest::new("anthropic.claude-3-sonnet-20240229-v1:0", "Hello").with_max_tokens(100);
+        let body = ModelFamily::Anthropic.build_request(&request);
+        assert_eq!(body["anthropic_version"], "bedrock-2023-05-31");
+        assert_eq!(body["messages"][0]["content"], "Hello");
+        assert_eq!(body["max_tokens"], 100);
+    }
+
+    #[test]
+    fn test_build_request_titan() {
+        let request = CompletionRequest::new("amazon.titan-text-express-v1", "Hello").with_max_tokens(50);
+        let body = ModelFamily::Titan.build_request(&request);
+        assert_eq!(body["inputText"], "Hello");
+        assert_eq!(body["textGenerationConfig"]["maxTokenCount"], 50);
+    }
+
+    #[test]
+    fn test_parse_response_anthropic() {
+        let json = serde_json::json!({
+            "content": [{"type": "text", "text": "Hi there"}],
+            "stop_reason": "end_turn",
+            "usage": {"input_tokens": 10, "output_tokens": 5},
+        });
+        let response = ModelFamily::Anthropic.parse_response("anthropic.claude-3-sonnet-20240229-v1:0", &json).unwrap();
+        assert_eq!(response.content, "Hi there");
+        assert_eq!(response.finish_reason, FinishReason::Stop);
+        assert_eq!(response.usage.prompt_tokens, 10);
+        assert_eq!(response.usage.completion_tokens, 5);
+    }
+
+    #[test]
+    fn test_parse_response_titan() {
+        let json = serde_json::json!({
+            "inputTextTokenCount": 8,
+            "results": [{"tokenCount": 12, "outputText": "Generated text", "completionReason": "FINISH"}],
+        });
+        let response = ModelFamily::Titan.parse_response("amazon.titan-text-express-v1", &json).unwrap();
+        assert_eq!(response.content, "Generated text");
+        assert_eq!(response.usage.prompt_tokens, 8);
+        assert_eq!(response.usage.completion_tokens, 12);
+    }
+
+    #[test]
+    fn test_extract_stream_text_anthropic() {
+        let chunk = serde_json::json!({
+            "type": "content_block_delta",
+            "delta": {"type": "text_delta", "text": "partial"},
+        });
+        assert_eq!(ModelFamily::Anthropic.extract_stream_text(&chunk), Some("partial".to_string()));
+
+        let other = serde_json::json!({"type": "message_start"});
+        assert_eq!(ModelFamily::Anthropic.extract_stream_text(&other), None);
+    }
+
+    #[test]
+    fn test_eventstream_next_frame_roundtrip() {
+        // Build a minimal frame: total_length(4) + headers_length(4) + prelude_crc(4)
+        // + headers(0) + payload + message_crc(4)
+        let payload = br#"{"bytes":"eyJvdXRwdXRUZXh0IjoiaGkifQ=="}"#; // {"outputText":"hi"}
+        let total_length = (12 + payload.len() + 4) as u32;
+
+        let mut frame = Vec::new();
+        frame.extend_from_slice(&total_length.to_be_bytes());
+        frame.extend_from_slice(&0u32.to_be_bytes()); // headers_length = 0
+        frame.extend_from_slice(&0u32.to_be_bytes()); // prelude crc (unchecked)
+        frame.extend_from_slice(payload);
+        frame.extend_from_slice(&0u32.to_be_bytes()); // message crc (unchecked)
+
+        let (frame_len, decoded) = eventstream::next_frame(&frame).expect("frame should decode");
+        assert_eq!(frame_len, frame.len());
+        assert_eq!(decoded.payload, payload);
+        assert!(!decoded.is_exception());
+    }
+
+    #[test]
+    fn test_eventstream_next_frame_incomplete_buffer() {
+        let partial = [0u8, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0];
+        assert!(eventstream::next_frame(&partial).is_none());
+    }
+
+    /// Encode one event-stream header entry: `name_len(1) name value_type(1) value_len(2) value`
+    fn encode_header(name: &str, value: &str) -> Vec<u8> {
+        let mut out = vec![name.len() as u8];
+        out.extend_from_slice(name.as_bytes());
+        out.push(7); // string value type
+        out.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        out.extend_from_slice(value.as_bytes());
+        out
+    }
+
+    #[test]
+    fn test_eventstream_next_frame_parses_exception_headers() {
+        let headers = [
+            encode_header(":message-type", "exception"),
+            encode_header(":exception-type", "ThrottlingException"),
+        ]
+        .concat();
+        let payload = br#"{"message":"Too many requests"}"#;
+        let total_length = (12 + headers.len() + payload.len() + 4) as u32;
+
+        let mut frame = Vec::new();
+        frame.extend_from_slice(&total_length.to_be_bytes());
+        frame.extend_from_slice(&(headers.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&0u32.to_be_bytes()); // prelude crc (unchecked)
+        frame.extend_from_slice(&headers);
+        frame.extend_from_slice(payload);
+        frame.extend_from_slice(&0u32.to_be_bytes()); // message crc (unchecked)
+
+        let (_, decoded) = eventstream::next_frame(&frame).expect("frame should decode");
+        assert!(decoded.is_exception());
+        assert_eq!(decoded.exception_type(), Some("ThrottlingException"));
+
+        let error = BedrockProvider::parse_stream_exception(&decoded);
+        assert!(matches!(error, ProviderError::RateLimitExceeded { .. }));
+    }
+
+    #[test]
+    fn test_decode_stream_payload_titan() {
+        let payload = br#"{"bytes":"eyJvdXRwdXRUZXh0IjoiaGkifQ=="}"#;
+        let text = decode_stream_payload("amazon.titan-text-express-v1", payload);
+        assert_eq!(text, Some("hi".to_string()));
+    }
+}
@@ -74,6 +74,8 @@ impl ProviderFactory {
     /// - `bedrock` - AWS Bedrock
     /// - `replicate` - Replicate
     /// - `perplexity` - Perplexity AI
+    /// - `openai-compatible` - Any OpenAI-compatible API (self-hosted gateways, etc.)
+    /// - `localai` - LocalAI, served through its OpenAI-compatible endpoint
     ///
     /// # Examples
     ///
@@ -99,6 +101,8 @@ impl ProviderFactory {
         registry.insert("bedrock".to_string(), create_bedrock as _);
         registry.insert("replicate".to_string(), create_replicate as _);
         registry.insert("perplexity".to_string(), create_perplexity as _);
+        registry.insert("openai-compatible".to_string(), create_openai_compatible as _);
+        registry.insert("localai".to_string(), create_openai_compatible as _);
 
         Self { _registry: registry }
     }
@@ -157,8 +161,9 @@ impl ProviderFactory {
             "bedrock" => create_bedrock(config),
             "replicate" => create_replicate(config),
             "perplexity" => create_perplexity(config),
+            "openai-compatible" | "openai_compatible" | "localai" => create_openai_compatible(config),
             _ => Err(ProviderError::InvalidRequest(format!(
-                "Unknown provider: {}. Supported providers: openai, anthropic, google, cohere, mistral, groq, together, huggingface, ollama, azure-openai, bedrock, replicate, perplexity",
+                "Unknown provider: {}. Supported providers: openai, anthropic, google, cohere, mistral, groq, together, huggingface, ollama, azure-openai, bedrock, replicate, perplexity, openai-compatible, localai",
                 provider_name
             ))),
         }
@@ -238,6 +243,8 @@ impl ProviderFactory {
             "bedrock".to_string(),
             "replicate".to_string(),
             "perplexity".to_string(),
+            "openai-compatible".to_string(),
+            "localai".to_string(),
         ]
     }
 }
@@ -341,6 +348,18 @@ fn create_ollama(config: &ProviderConfig) -> Result<Box<dyn Provider>, ProviderE
     Ok(Box::new(provider))
 }
 
+/// Creates a provider for any OpenAI-compatible API (e.g. LocalAI, vLLM's
+/// OpenAI front-end, or a self-hosted gateway) from configuration.
+///
+/// Unlike `create_openai`, the API key is optional: most self-hosted
+/// OpenAI-compatible servers don't check it, so a missing environment
+/// variable falls back to a placeholder instead of failing setup.
+fn create_openai_compatible(config: &ProviderConfig) -> Result<Box<dyn Provider>, ProviderError> {
+    let api_key = std::env::var(&config.api_key_env).unwrap_or_else(|_| "not-needed".to_string());
+    let provider = OpenAIProvider::with_base_url(api_key, config.base_url.clone())?;
+    Ok(Box::new(provider))
+}
+
 /// Creates an Azure OpenAI provider instance from configuration.
 fn create_azure_openai(config: &ProviderConfig) -> Result<Box<dyn Provider>, ProviderError> {
     let api_key = std::env::var(&config.api_key_env)
@@ -499,6 +518,30 @@ mod tests {
         std::env::remove_var(&config.api_key_env);
     }
 
+    #[test]
+    fn test_create_openai_compatible_without_api_key() {
+        let factory = ProviderFactory::new();
+        let mut config = test_config("openai-compatible");
+        config.base_url = "http://localhost:8080/v1".to_string();
+
+        // No API key set - should still succeed with a placeholder
+        std::env::remove_var(&config.api_key_env);
+
+        let result = factory.create("openai-compatible", &config);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_create_localai_alias() {
+        let factory = ProviderFactory::new();
+        let mut config = test_config("localai");
+        config.base_url = "http://localhost:8081/v1".to_string();
+        std::env::remove_var(&config.api_key_env);
+
+        let result = factory.create("localai", &config);
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_case_insensitive_provider_names() {
         let factory = ProviderFactory::new();
@@ -0,0 +1,441 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Multi-provider fallback and A/B routing across clients.
+//!
+//! This module provides two composite [`Provider`] implementations that wrap
+//! a set of underlying providers rather than talking to an API directly:
+//!
+//! - [`FallbackProvider`] tries providers in order, falling through to the
+//!   next one when the current provider returns a retryable error.
+//! - [`ABRouter`] splits traffic across providers by weight, useful for
+//!   comparing two providers (or two deployments of the same provider) in
+//!   production without changing calling code.
+
+use super::{CompletionRequest, CompletionResponse, ModelInfo, Provider, ProviderError, ResponseStream};
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::warn;
+
+/// Wraps an ordered list of providers, falling through to the next one when
+/// the current provider fails with a retryable error.
+///
+/// Providers are tried in the order they were registered. If every provider
+/// fails, the error from the last provider tried is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use llm_test_bench_core::providers::{FallbackProvider, OpenAIProvider, AnthropicProvider};
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let primary = OpenAIProvider::new("sk-...".to_string())?;
+/// let backup = AnthropicProvider::new("sk-ant-...".to_string());
+///
+/// let provider = FallbackProvider::new(vec![Box::new(primary), Box::new(backup)]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct FallbackProvider {
+    providers: Vec<Box<dyn Provider>>,
+}
+
+impl FallbackProvider {
+    /// Creates a new fallback chain from an ordered list of providers.
+    ///
+    /// The first provider is the primary; later providers are only used if
+    /// earlier ones fail with a retryable error.
+    pub fn new(providers: Vec<Box<dyn Provider>>) -> Self {
+        Self { providers }
+    }
+}
+
+#[async_trait]
+impl Provider for FallbackProvider {
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, ProviderError> {
+        let mut last_error = None;
+
+        for (index, provider) in self.providers.iter().enumerate() {
+            match provider.complete(request.clone()).await {
+                Ok(response) => return Ok(response),
+                Err(error) => {
+                    let is_last = index == self.providers.len() - 1;
+                    if !error.is_retryable() || is_last {
+                        return Err(error);
+                    }
+
+                    warn!(
+                        "Provider '{}' failed ({}), falling back to next provider",
+                        provider.name(),
+                        error
+                    );
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            ProviderError::InternalError("FallbackProvider has no providers configured".to_string())
+        }))
+    }
+
+    async fn stream(&self, request: CompletionRequest) -> Result<ResponseStream, ProviderError> {
+        let mut last_error = None;
+
+        for (index, provider) in self.providers.iter().enumerate() {
+            match provider.stream(request.clone()).await {
+                Ok(stream) => return Ok(stream),
+                Err(error) => {
+                    let is_last = index == self.providers.len() - 1;
+                    if !error.is_retryable() || is_last {
+                        return Err(error);
+                    }
+
+                    warn!(
+                        "Provider '{}' failed to start stream ({}), falling back to next provider",
+                        provider.name(),
+                        error
+                    );
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            ProviderError::InternalError("FallbackProvider has no providers configured".to_string())
+        }))
+    }
+
+    fn supported_models(&self) -> Vec<ModelInfo> {
+        self.providers
+            .first()
+            .map(|provider| provider.supported_models())
+            .unwrap_or_default()
+    }
+
+    fn max_context_length(&self, model: &str) -> Option<usize> {
+        self.providers
+            .first()
+            .and_then(|provider| provider.max_context_length(model))
+    }
+
+    fn name(&self) -> &str {
+        "fallback"
+    }
+
+    async fn validate_config(&self) -> Result<(), ProviderError> {
+        for provider in &self.providers {
+            provider.validate_config().await?;
+        }
+        Ok(())
+    }
+
+    fn estimate_tokens(&self, text: &str, model: &str) -> Result<usize, ProviderError> {
+        self.providers
+            .first()
+            .ok_or_else(|| ProviderError::InternalError("FallbackProvider has no providers configured".to_string()))?
+            .estimate_tokens(text, model)
+    }
+}
+
+/// A weighted variant used for A/B routing across clients.
+struct WeightedProvider {
+    provider: Box<dyn Provider>,
+    weight: u32,
+}
+
+/// Routes requests across a set of providers according to configured
+/// weights, for A/B testing providers (or two configurations of the same
+/// provider) against live traffic.
+///
+/// Routing is deterministic per call via a round-robin counter weighted by
+/// each provider's share, so a 70/30 split sends 70 of every 100 consecutive
+/// requests to the first provider, as one contiguous run, then 30 to the second.
+///
+/// # Examples
+///
+/// ```no_run
+/// use llm_test_bench_core::providers::{ABRouter, OpenAIProvider};
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let variant_a = OpenAIProvider::new("sk-...".to_string())?;
+/// let variant_b = OpenAIProvider::with_base_url("sk-...".to_string(), "https://api.openai.com/v1".to_string())?;
+///
+/// let router = ABRouter::new(vec![
+///     (Box::new(variant_a), 70),
+///     (Box::new(variant_b), 30),
+/// ]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ABRouter {
+    providers: Vec<WeightedProvider>,
+    total_weight: u32,
+    counter: AtomicU64,
+}
+
+impl ABRouter {
+    /// Creates a new A/B router from `(provider, weight)` pairs.
+    ///
+    /// Weights are relative, not percentages - `(a, 70), (b, 30)` and
+    /// `(a, 7), (b, 3)` give the same long-run split, though the larger total
+    /// serves each provider in longer contiguous runs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `providers` is empty or all weights are zero.
+    pub fn new(providers: Vec<(Box<dyn Provider>, u32)>) -> Self {
+        assert!(!providers.is_empty(), "ABRouter requires at least one provider");
+
+        let total_weight: u32 = providers.iter().map(|(_, weight)| weight).sum();
+        assert!(total_weight > 0, "ABRouter requires at least one non-zero weight");
+
+        let providers = providers
+            .into_iter()
+            .map(|(provider, weight)| WeightedProvider { provider, weight })
+            .collect();
+
+        Self {
+            providers,
+            total_weight,
+            counter: AtomicU64::new(0),
+        }
+    }
+
+    /// Selects a provider for the next request using a weighted round-robin
+    /// counter, and returns its index alongside a reference to it.
+    fn select(&self) -> (usize, &dyn Provider) {
+        let slot = self.counter.fetch_add(1, Ordering::Relaxed) % self.total_weight as u64;
+
+        let mut cumulative = 0u32;
+        for (index, weighted) in self.providers.iter().enumerate() {
+            cumulative += weighted.weight;
+            if slot < cumulative as u64 {
+                return (index, weighted.provider.as_ref());
+            }
+        }
+
+        // Rounding edge case: fall back to the last provider.
+        let last = self.providers.len() - 1;
+        (last, self.providers[last].provider.as_ref())
+    }
+
+    /// Returns the name of the provider that would be selected for variant
+    /// `index` (0-based), for logging which arm served a given request.
+    pub fn variant_name(&self, index: usize) -> Option<&str> {
+        self.providers.get(index).map(|weighted| weighted.provider.name())
+    }
+}
+
+#[async_trait]
+impl Provider for ABRouter {
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, ProviderError> {
+        let (_, provider) = self.select();
+        provider.complete(request).await
+    }
+
+    async fn stream(&self, request: CompletionRequest) -> Result<ResponseStream, ProviderError> {
+        let (_, provider) = self.select();
+        provider.stream(request).await
+    }
+
+    fn supported_models(&self) -> Vec<ModelInfo> {
+        self.providers
+            .first()
+            .map(|weighted| weighted.provider.supported_models())
+            .unwrap_or_default()
+    }
+
+    fn max_context_length(&self, model: &str) -> Option<usize> {
+        self.providers
+            .first()
+            .and_then(|weighted| weighted.provider.max_context_length(model))
+    }
+
+    fn name(&self) -> &str {
+        "ab-router"
+    }
+
+    async fn validate_config(&self) -> Result<(), ProviderError> {
+        for weighted in &self.providers {
+            weighted.provider.validate_config().await?;
+        }
+        Ok(())
+    }
+
+    fn estimate_tokens(&self, text: &str, model: &str) -> Result<usize, ProviderError> {
+        self.providers
+            .first()
+            .ok_or_else(|| ProviderError::InternalError("ABRouter has no providers configured".to_string()))?
+            .provider
+            .estimate_tokens(text, model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::types::{FinishReason, TokenUsage};
+
+    struct MockProvider {
+        name: &'static str,
+        result: Result<&'static str, ProviderError>,
+    }
+
+    #[async_trait]
+    impl Provider for MockProvider {
+        async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, ProviderError> {
+            match &self.result {
+                Ok(content) => Ok(CompletionResponse {
+                    id: "mock-1".to_string(),
+                    content: content.to_string(),
+                    model: request.model,
+                    usage: TokenUsage {
+                        prompt_tokens: 1,
+                        completion_tokens: 1,
+                        total_tokens: 2,
+                    },
+                    finish_reason: FinishReason::Stop,
+                    created_at: chrono::Utc::now(),
+                }),
+                Err(error) => Err(clone_error(error)),
+            }
+        }
+
+        async fn stream(&self, _request: CompletionRequest) -> Result<ResponseStream, ProviderError> {
+            Err(ProviderError::InternalError("not implemented in mock".to_string()))
+        }
+
+        fn supported_models(&self) -> Vec<ModelInfo> {
+            vec![]
+        }
+
+        fn max_context_length(&self, _model: &str) -> Option<usize> {
+            None
+        }
+
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn validate_config(&self) -> Result<(), ProviderError> {
+            Ok(())
+        }
+
+        fn estimate_tokens(&self, text: &str, _model: &str) -> Result<usize, ProviderError> {
+            Ok(text.len())
+        }
+    }
+
+    fn clone_error(error: &ProviderError) -> ProviderError {
+        match error {
+            ProviderError::RateLimitExceeded { retry_after } => {
+                ProviderError::RateLimitExceeded { retry_after: *retry_after }
+            }
+            ProviderError::InvalidApiKey => ProviderError::InvalidApiKey,
+            other => ProviderError::InternalError(other.to_string()),
+        }
+    }
+
+    fn request() -> CompletionRequest {
+        CompletionRequest {
+            model: "test-model".to_string(),
+            prompt: "hello".to_string(),
+            max_tokens: None,
+            temperature: None,
+            top_p: None,
+            stop: None,
+            stream: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fallback_uses_primary_on_success() {
+        let primary = MockProvider { name: "primary", result: Ok("from primary") };
+        let backup = MockProvider { name: "backup", result: Ok("from backup") };
+
+        let provider = FallbackProvider::new(vec![Box::new(primary), Box::new(backup)]);
+        let response = provider.complete(request()).await.unwrap();
+        assert_eq!(response.content, "from primary");
+    }
+
+    #[tokio::test]
+    async fn test_fallback_falls_through_on_retryable_error() {
+        let primary = MockProvider {
+            name: "primary",
+            result: Err(ProviderError::RateLimitExceeded { retry_after: None }),
+        };
+        let backup = MockProvider { name: "backup", result: Ok("from backup") };
+
+        let provider = FallbackProvider::new(vec![Box::new(primary), Box::new(backup)]);
+        let response = provider.complete(request()).await.unwrap();
+        assert_eq!(response.content, "from backup");
+    }
+
+    #[tokio::test]
+    async fn test_fallback_does_not_fall_through_on_non_retryable_error() {
+        let primary = MockProvider { name: "primary", result: Err(ProviderError::InvalidApiKey) };
+        let backup = MockProvider { name: "backup", result: Ok("from backup") };
+
+        let provider = FallbackProvider::new(vec![Box::new(primary), Box::new(backup)]);
+        let result = provider.complete(request()).await;
+        assert!(matches!(result, Err(ProviderError::InvalidApiKey)));
+    }
+
+    #[tokio::test]
+    async fn test_fallback_returns_last_error_when_all_fail() {
+        let primary = MockProvider {
+            name: "primary",
+            result: Err(ProviderError::RateLimitExceeded { retry_after: None }),
+        };
+        let backup = MockProvider {
+            name: "backup",
+            result: Err(ProviderError::RateLimitExceeded { retry_after: None }),
+        };
+
+        let provider = FallbackProvider::new(vec![Box::new(primary), Box::new(backup)]);
+        let result = provider.complete(request()).await;
+        assert!(matches!(result, Err(ProviderError::RateLimitExceeded { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_ab_router_splits_by_weight() {
+        let variant_a = MockProvider { name: "a", result: Ok("from a") };
+        let variant_b = MockProvider { name: "b", result: Ok("from b") };
+
+        let router = ABRouter::new(vec![(Box::new(variant_a), 2), (Box::new(variant_b), 1)]);
+
+        let mut from_a = 0;
+        let mut from_b = 0;
+        for _ in 0..6 {
+            match router.complete(request()).await.unwrap().content.as_str() {
+                "from a" => from_a += 1,
+                "from b" => from_b += 1,
+                other => panic!("unexpected content: {other}"),
+            }
+        }
+
+        assert_eq!(from_a, 4);
+        assert_eq!(from_b, 2);
+    }
+
+    #[test]
+    fn test_ab_router_variant_name() {
+        let variant_a = MockProvider { name: "a", result: Ok("from a") };
+        let variant_b = MockProvider { name: "b", result: Ok("from b") };
+
+        let router = ABRouter::new(vec![(Box::new(variant_a), 1), (Box::new(variant_b), 1)]);
+        assert_eq!(router.variant_name(0), Some("a"));
+        assert_eq!(router.variant_name(1), Some("b"));
+        assert_eq!(router.variant_name(2), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one provider")]
+    fn test_ab_router_rejects_empty_providers() {
+        let _ = ABRouter::new(vec![]);
+    }
+}
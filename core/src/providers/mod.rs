@@ -12,7 +12,8 @@
 //! - **Core abstractions**: The [`Provider`] trait and related types
 //! - **Error handling**: Comprehensive error types for provider operations
 //! - **Shared types**: Common request/response structures
-//! - **Provider implementations**: OpenAI, Anthropic, and future providers
+//! - **Provider implementations**: OpenAI, Anthropic, self-hosted
+//!   OpenAI-compatible servers (LocalAI, etc.), and future providers
 //! - **Factory pattern**: For creating provider instances from configuration
 //!
 //! # Architecture
@@ -145,6 +146,7 @@ pub mod azure_openai;
 pub mod bedrock;
 pub mod replicate;
 pub mod perplexity;
+pub mod fallback;
 
 // Re-export commonly used types
 pub use error::ProviderError;
@@ -168,3 +170,4 @@ pub use openai::OpenAIProvider;
 pub use perplexity::PerplexityProvider;
 pub use replicate::ReplicateProvider;
 pub use together::TogetherProvider;
+pub use fallback::{ABRouter, FallbackProvider};
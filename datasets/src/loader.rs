@@ -49,7 +49,8 @@ impl DatasetLoader {
             }
         };
 
-        // Validate schema
+        // Validate schema (including media limits and `{{media:N}}` references,
+        // both enforced as part of the `Validate` derive)
         dataset.validate().map_err(|e| {
             DatasetError::ValidationError(format!("Dataset validation failed: {}", e))
         })?;
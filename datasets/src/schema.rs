@@ -10,6 +10,7 @@
 //! using serde_valid. Datasets can be loaded from JSON or YAML files and validated
 //! against the schema requirements.
 
+use llm_test_bench_core::multimodal::{AudioInput, ImageInput, VideoInput};
 use serde::{Deserialize, Serialize};
 use serde_valid::Validate;
 use std::collections::HashMap;
@@ -35,6 +36,7 @@ use std::collections::HashMap;
 ///             expected: None,
 ///             references: None,
 ///             config: None,
+///             attachments: None,
 ///             metadata: None,
 ///         }
 ///     ],
@@ -45,6 +47,8 @@ use std::collections::HashMap;
 /// assert!(dataset.validate().is_ok());
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[rule(validate_media_limits_rule(test_cases, defaults))]
+#[rule(validate_media_refs_rule(test_cases))]
 pub struct Dataset {
     /// Dataset name (required, minimum length 1)
     #[validate(min_length = 1)]
@@ -71,6 +75,7 @@ pub struct Dataset {
 ///
 /// Test cases support templating via the `{{variable}}` syntax. Variables
 /// are substituted at runtime using the values in the `variables` map.
+/// `{{media:N}}` refers positionally into `attachments`.
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct TestCase {
     /// Unique test case identifier (required, minimum length 1)
@@ -96,10 +101,70 @@ pub struct TestCase {
     /// Per-test configuration overrides (optional)
     pub config: Option<TestConfig>,
 
+    /// Multimodal attachments for this test case (optional)
+    ///
+    /// Wraps `llm_test_bench_core::multimodal` input types directly, so an
+    /// attachment carries the same base64/URL/path/frame variants and
+    /// dimension/codec/duration metadata a provider call site understands.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<MediaAttachment>>,
+
     /// Test-specific metadata (optional)
     pub metadata: Option<HashMap<String, serde_json::Value>>,
 }
 
+/// The kind of media wrapped by a [`MediaAttachment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaKind {
+    /// A still image
+    Image,
+    /// An audio clip
+    Audio,
+    /// A video clip
+    Video,
+}
+
+/// A multimodal attachment for a test case.
+///
+/// Wraps the corresponding `llm_test_bench_core::multimodal` input type
+/// directly, so an attachment is exactly what a provider call site expects:
+/// base64 data, a URL, a local path, or (for video) frames/HLS, each with
+/// whatever dimension/codec/duration metadata the provider needs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum MediaAttachment {
+    /// An image attachment
+    Image(ImageInput),
+    /// An audio attachment
+    Audio(AudioInput),
+    /// A video attachment
+    Video(VideoInput),
+}
+
+impl MediaAttachment {
+    /// Returns the [`MediaKind`] of this attachment.
+    pub fn kind(&self) -> MediaKind {
+        match self {
+            MediaAttachment::Image(_) => MediaKind::Image,
+            MediaAttachment::Audio(_) => MediaKind::Audio,
+            MediaAttachment::Video(_) => MediaKind::Video,
+        }
+    }
+
+    /// Returns the local file path this attachment references, if it is a
+    /// `Path`-variant input rather than a URL, inline base64 payload, or
+    /// (for video) frames/HLS.
+    pub fn local_path(&self) -> Option<&std::path::Path> {
+        match self {
+            MediaAttachment::Image(ImageInput::Path { path, .. }) => Some(path),
+            MediaAttachment::Audio(AudioInput::Path { path, .. }) => Some(path),
+            MediaAttachment::Video(VideoInput::Path { path, .. }) => Some(path),
+            _ => None,
+        }
+    }
+}
+
 /// Default configuration applied to all test cases unless overridden.
 ///
 /// These settings provide dataset-wide defaults that can be overridden
@@ -121,6 +186,74 @@ pub struct DefaultConfig {
     /// Default stop sequences
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stop: Option<Vec<String>>,
+
+    /// Ingest constraints applied to every media attachment on load
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_limits: Option<MediaLimits>,
+}
+
+/// Ingest constraints for media attachments, declared once on
+/// [`DefaultConfig`] and enforced against every [`MediaAttachment`] on load.
+///
+/// Only file size and media kind are checked here, without decoding or
+/// probing the referenced bytes. Dimension, duration, and codec limits are
+/// the benchmark runner's responsibility once it actually loads the media.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MediaLimits {
+    /// Maximum size, in bytes, of a local media file (remote URLs are skipped)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_file_size_bytes: Option<u64>,
+
+    /// Media kinds allowed in this dataset (empty means no restriction)
+    #[serde(default)]
+    pub allowed_kinds: Vec<MediaKind>,
+}
+
+impl MediaLimits {
+    /// Creates an unconstrained set of media limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum local file size, in bytes.
+    pub fn with_max_file_size_bytes(mut self, max_file_size_bytes: u64) -> Self {
+        self.max_file_size_bytes = Some(max_file_size_bytes);
+        self
+    }
+
+    /// Restricts which media kinds are allowed.
+    pub fn with_allowed_kinds(mut self, allowed_kinds: Vec<MediaKind>) -> Self {
+        self.allowed_kinds = allowed_kinds;
+        self
+    }
+
+    /// Validates a single media attachment against these limits.
+    ///
+    /// Only local paths (not `http://`/`https://` sources) are size-checked;
+    /// a missing local file is not treated as a violation here, since
+    /// fetching/opening it is the loader's job.
+    pub fn validate_media(&self, media: &MediaAttachment) -> Result<(), String> {
+        let kind = media.kind();
+        if !self.allowed_kinds.is_empty() && !self.allowed_kinds.contains(&kind) {
+            return Err(format!("media kind {:?} is not in the allowed list", kind));
+        }
+
+        if let Some(limit) = self.max_file_size_bytes {
+            if let Some(path) = media.local_path() {
+                if let Ok(file_metadata) = std::fs::metadata(path) {
+                    let actual = file_metadata.len();
+                    if actual > limit {
+                        return Err(format!(
+                            "media file '{}' is {} bytes, exceeding the limit of {} bytes",
+                            path.display(), actual, limit
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Per-test configuration that overrides dataset defaults.
@@ -206,6 +339,60 @@ impl Dataset {
     pub fn is_empty(&self) -> bool {
         self.test_cases.is_empty()
     }
+
+}
+
+/// Validates every test case's media attachments against the dataset's
+/// declared `defaults.media_limits`, if any are set.
+///
+/// Wired into [`Dataset`]'s `Validate` derive via `#[rule(...)]`, so it runs
+/// as part of `dataset.validate()` and can't be skipped by callers.
+fn validate_media_limits_rule(
+    test_cases: &Vec<TestCase>,
+    defaults: &Option<DefaultConfig>,
+) -> Result<(), serde_valid::validation::Error> {
+    let Some(limits) = defaults.as_ref().and_then(|defaults| defaults.media_limits.as_ref()) else {
+        return Ok(());
+    };
+
+    for test_case in test_cases {
+        let Some(attachments) = test_case.attachments.as_ref() else {
+            continue;
+        };
+        for attachment in attachments {
+            limits.validate_media(attachment).map_err(|error| {
+                serde_valid::validation::Error::Custom(format!(
+                    "test case '{}': {}",
+                    test_case.id, error
+                ))
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates every test case's prompt against its own `attachments` list:
+/// each `{{media:N}}` placeholder must reference an attachment that
+/// actually exists.
+///
+/// Wired into [`Dataset`]'s `Validate` derive via `#[rule(...)]`, so it runs
+/// as part of `dataset.validate()` and can't be skipped by callers.
+fn validate_media_refs_rule(test_cases: &Vec<TestCase>) -> Result<(), serde_valid::validation::Error> {
+    for test_case in test_cases {
+        let attachment_count = test_case.attachments.as_ref().map_or(0, Vec::len);
+
+        for index in crate::template::TemplateEngine::extract_media_refs(&test_case.prompt) {
+            if index >= attachment_count {
+                return Err(serde_valid::validation::Error::Custom(format!(
+                    "test case '{}': prompt references {{{{media:{}}}}}, but only {} attachment(s) are attached",
+                    test_case.id, index, attachment_count
+                )));
+            }
+        }
+    }
+
+    Ok(())
 }
 
 impl TestCase {
@@ -229,6 +416,7 @@ impl TestCase {
             expected: None,
             references: None,
             config: None,
+            attachments: None,
             metadata: None,
         }
     }
@@ -270,6 +458,23 @@ impl TestCase {
         self.config = Some(config);
         self
     }
+
+    /// Set all attachments at once, making this a multimodal test case.
+    pub fn with_attachments(mut self, attachments: Vec<MediaAttachment>) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    /// Add a single attachment, making this a multimodal test case.
+    pub fn add_attachment(mut self, attachment: MediaAttachment) -> Self {
+        self.attachments.get_or_insert_with(Vec::new).push(attachment);
+        self
+    }
+
+    /// Returns `true` if this test case has any attachments.
+    pub fn is_multimodal(&self) -> bool {
+        self.attachments.as_ref().is_some_and(|attachments| !attachments.is_empty())
+    }
 }
 
 impl DefaultConfig {
@@ -280,6 +485,7 @@ impl DefaultConfig {
             max_tokens: None,
             top_p: None,
             stop: None,
+            media_limits: None,
         }
     }
 
@@ -306,6 +512,12 @@ impl DefaultConfig {
         self.stop = Some(stop);
         self
     }
+
+    /// Set the media ingest limits applied to every attachment on load.
+    pub fn with_media_limits(mut self, media_limits: MediaLimits) -> Self {
+        self.media_limits = Some(media_limits);
+        self
+    }
 }
 
 impl Default for DefaultConfig {
@@ -470,4 +682,159 @@ mod tests {
         assert_eq!(config.model, Some("gpt-4".to_string()));
         assert_eq!(config.temperature, Some(0.0));
     }
+
+    fn image_attachment(source: &str) -> MediaAttachment {
+        if let Some(url) = source.strip_prefix("http://").or_else(|| source.strip_prefix("https://")) {
+            let _ = url;
+            MediaAttachment::Image(ImageInput::Url {
+                url: source.to_string(),
+                metadata: None,
+            })
+        } else {
+            MediaAttachment::Image(ImageInput::Path {
+                path: std::path::PathBuf::from(source),
+                metadata: None,
+            })
+        }
+    }
+
+    fn video_attachment(source: &str) -> MediaAttachment {
+        MediaAttachment::Video(VideoInput::Url {
+            url: source.to_string(),
+            metadata: None,
+        })
+    }
+
+    #[test]
+    fn test_media_attachment_kind_and_local_path() {
+        let remote = image_attachment("https://example.com/cat.jpg");
+        assert_eq!(remote.kind(), MediaKind::Image);
+        assert_eq!(remote.local_path(), None);
+
+        let local = image_attachment("cat.jpg");
+        assert_eq!(local.kind(), MediaKind::Image);
+        assert_eq!(local.local_path(), Some(std::path::Path::new("cat.jpg")));
+    }
+
+    #[test]
+    fn test_test_case_with_attachments_is_multimodal() {
+        let test = TestCase::new("test-1", "Describe this image")
+            .add_attachment(image_attachment("cat.jpg"));
+
+        assert!(test.is_multimodal());
+        assert_eq!(test.attachments.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_test_case_without_attachments_is_not_multimodal() {
+        let test = TestCase::new("test-1", "What is Rust?");
+        assert!(!test.is_multimodal());
+    }
+
+    #[test]
+    fn test_test_case_with_multiple_attachments() {
+        let test = TestCase::new("test-1", "Compare these images")
+            .add_attachment(image_attachment("a.jpg"))
+            .add_attachment(image_attachment("b.jpg"));
+
+        assert_eq!(test.attachments.as_ref().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_test_case_with_attachments_builder() {
+        let test = TestCase::new("test-1", "Compare these images")
+            .with_attachments(vec![image_attachment("a.jpg"), image_attachment("b.jpg")]);
+
+        assert_eq!(test.attachments.as_ref().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_validate_media_limits_passes_with_no_limits_declared() {
+        let mut dataset = Dataset::new("test", "1.0.0");
+        dataset.add_test_case(
+            TestCase::new("t1", "prompt").add_attachment(image_attachment("cat.jpg")),
+        );
+
+        assert!(dataset.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_media_limits_rejects_disallowed_kind() {
+        let mut dataset = Dataset::new("test", "1.0.0")
+            .with_defaults(DefaultConfig::new().with_media_limits(
+                MediaLimits::new().with_allowed_kinds(vec![MediaKind::Image]),
+            ));
+        dataset.add_test_case(
+            TestCase::new("t1", "prompt")
+                .add_attachment(video_attachment("https://example.com/clip.mp4")),
+        );
+
+        let error = dataset.validate().unwrap_err().to_string();
+        assert!(error.contains("t1"));
+        assert!(error.contains("Video"));
+    }
+
+    #[test]
+    fn test_validate_media_limits_rejects_oversized_local_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("big.jpg");
+        std::fs::write(&file_path, vec![0u8; 1024]).unwrap();
+
+        let mut dataset = Dataset::new("test", "1.0.0").with_defaults(
+            DefaultConfig::new().with_media_limits(MediaLimits::new().with_max_file_size_bytes(100)),
+        );
+        dataset.add_test_case(
+            TestCase::new("t1", "prompt")
+                .add_attachment(image_attachment(file_path.to_str().unwrap())),
+        );
+
+        let error = dataset.validate().unwrap_err().to_string();
+        assert!(error.contains("exceeding the limit"));
+    }
+
+    #[test]
+    fn test_validate_media_limits_skips_remote_sources() {
+        let mut dataset = Dataset::new("test", "1.0.0").with_defaults(
+            DefaultConfig::new().with_media_limits(MediaLimits::new().with_max_file_size_bytes(1)),
+        );
+        dataset.add_test_case(
+            TestCase::new("t1", "prompt")
+                .add_attachment(image_attachment("https://example.com/huge.jpg")),
+        );
+
+        assert!(dataset.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_media_refs_passes_when_indices_in_range() {
+        let mut dataset = Dataset::new("test", "1.0.0");
+        dataset.add_test_case(
+            TestCase::new("t1", "Compare {{media:0}} to {{media:1}}")
+                .add_attachment(image_attachment("cat.jpg"))
+                .add_attachment(image_attachment("dog.jpg")),
+        );
+
+        assert!(dataset.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_media_refs_rejects_out_of_range_index() {
+        let mut dataset = Dataset::new("test", "1.0.0");
+        dataset.add_test_case(
+            TestCase::new("t1", "What's in {{media:1}}?")
+                .add_attachment(image_attachment("cat.jpg")),
+        );
+
+        let error = dataset.validate().unwrap_err().to_string();
+        assert!(error.contains("t1"));
+        assert!(error.contains("media:1"));
+    }
+
+    #[test]
+    fn test_validate_media_refs_rejects_reference_with_no_attachments() {
+        let mut dataset = Dataset::new("test", "1.0.0");
+        dataset.add_test_case(TestCase::new("t1", "What's in {{media:0}}?"));
+
+        assert!(dataset.validate().is_err());
+    }
 }
@@ -88,6 +88,32 @@ impl TemplateEngine {
     pub fn has_variables(template: &str) -> bool {
         template.contains("{{") && template.contains("}}")
     }
+
+    /// Extract the media attachment indices referenced via `{{media:N}}`
+    /// placeholders, in the order they appear in the template.
+    ///
+    /// `{{media:N}}` is positional syntax for "the Nth attachment in this
+    /// test case's `attachments` list" (0-indexed); it is distinct from
+    /// `{{variable}}` substitution since attachments aren't text, so
+    /// [`Self::render`] leaves these placeholders untouched rather than
+    /// substituting them. [`crate::schema::Dataset`]'s `Validate` derive
+    /// uses this to check every referenced index actually has a matching
+    /// attachment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use llm_test_bench_datasets::template::TemplateEngine;
+    ///
+    /// let refs = TemplateEngine::extract_media_refs("What's in {{media:0}} vs {{media:1}}?");
+    /// assert_eq!(refs, vec![0, 1]);
+    /// ```
+    pub fn extract_media_refs(template: &str) -> Vec<usize> {
+        let re = Regex::new(r"\{\{media:(\d+)\}\}").unwrap();
+        re.captures_iter(template)
+            .filter_map(|caps| caps[1].parse().ok())
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -173,6 +199,25 @@ mod tests {
         assert_eq!(vars.len(), 2); // Duplicates included
     }
 
+    #[test]
+    fn test_extract_media_refs() {
+        let refs = TemplateEngine::extract_media_refs("Compare {{media:0}} to {{media:1}}.");
+        assert_eq!(refs, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_extract_media_refs_none() {
+        let refs = TemplateEngine::extract_media_refs("No media references here");
+        assert_eq!(refs, Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_render_ignores_media_refs() {
+        let vars = HashMap::new();
+        let result = TemplateEngine::render("See {{media:0}}", &vars).unwrap();
+        assert_eq!(result, "See {{media:0}}");
+    }
+
     #[test]
     fn test_has_variables() {
         assert!(TemplateEngine::has_variables("Hello {{name}}"));
@@ -64,8 +64,9 @@ async fn main() -> Result<()> {
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
 
         // Record latency
+        let trace_id = format!("req_{}", i);
         let latency = 0.5 + (i as f64 * 0.1);
-        monitoring.record_latency("openai", latency);
+        monitoring.record_latency("openai", latency, Some(&trace_id));
 
         // Record tokens
         let input_tokens = 100 + (i * 10);
@@ -74,7 +75,7 @@ async fn main() -> Result<()> {
 
         // Record cost
         let cost = 0.001 + (i as f64 * 0.0001);
-        monitoring.record_cost("openai", cost);
+        monitoring.record_cost("openai", cost, Some(&trace_id));
 
         println!("  ✓ Latency: {:.2}s", latency);
         println!("  ✓ Tokens: {} in, {} out", input_tokens, output_tokens);